@@ -0,0 +1,12 @@
+-- Nonces are issued by the server to a wallet, whether or not it has a profile, and are
+-- deleted once used or expired
+CREATE TABLE IF NOT EXISTS login_nonces (
+    nonce VARCHAR(255) PRIMARY KEY,
+    address VARCHAR(255) NOT NULL,
+    expires_at TIMESTAMP WITH TIME ZONE NOT NULL,
+    created_at TIMESTAMP WITH TIME ZONE NOT NULL DEFAULT NOW()
+);
+CREATE INDEX IF NOT EXISTS idx_login_nonces_expires_at ON login_nonces (expires_at);
+CREATE INDEX IF NOT EXISTS idx_login_nonces_address ON login_nonces (LOWER(address), expires_at);
+
+ALTER TABLE profiles DROP COLUMN IF EXISTS login_nonce;
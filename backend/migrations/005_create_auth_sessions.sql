@@ -0,0 +1,14 @@
+-- Sessions opened by signing in, kept alive by rotating their refresh token. Only hashes of
+-- the refresh tokens are stored, along with the one last replaced to detect its reuse.
+CREATE TABLE IF NOT EXISTS auth_sessions (
+    id UUID PRIMARY KEY,
+    address VARCHAR(255) NOT NULL,
+    refresh_token_hash VARCHAR(64) NOT NULL UNIQUE,
+    previous_refresh_token_hash VARCHAR(64),
+    expires_at TIMESTAMP WITH TIME ZONE NOT NULL,
+    revoked_at TIMESTAMP WITH TIME ZONE,
+    created_at TIMESTAMP WITH TIME ZONE NOT NULL DEFAULT NOW()
+);
+CREATE INDEX IF NOT EXISTS idx_auth_sessions_address ON auth_sessions (LOWER(address));
+CREATE INDEX IF NOT EXISTS idx_auth_sessions_previous_refresh_token_hash
+    ON auth_sessions (previous_refresh_token_hash);
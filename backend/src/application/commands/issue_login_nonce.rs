@@ -0,0 +1,38 @@
+use chrono::{Duration, Utc};
+use std::sync::Arc;
+
+use crate::application::dtos::NonceResponse;
+use crate::domain::repositories::NonceRepository;
+use crate::domain::value_objects::{Nonce, WalletAddress};
+
+/// How long a wallet has to sign in with a nonce once it is issued.
+const NONCE_LIFETIME_MINUTES: i64 = 5;
+
+/// Nonces an address may hold at once, e.g. to sign in from several tabs, so that requesting
+/// nonces in a loop cannot fill the store.
+const MAX_OUTSTANDING_NONCES: i64 = 5;
+
+/// Issues a fresh nonce the next sign-in message of the address must carry, whether or not the
+/// address has a profile.
+pub async fn issue_login_nonce(
+    nonce_repository: Arc<dyn NonceRepository>,
+    address: String,
+) -> Result<NonceResponse, String> {
+    let wallet_address = WalletAddress::new(address)?;
+
+    let nonce = Nonce::new();
+    let expires_at = Utc::now() + Duration::minutes(NONCE_LIFETIME_MINUTES);
+    let saved = nonce_repository
+        .save(&wallet_address, &nonce, expires_at, MAX_OUTSTANDING_NONCES)
+        .await
+        .map_err(|e| format!("Error issuing nonce: {}", e))?;
+    if !saved {
+        return Err("Too many nonces issued to this address, try again later".to_string());
+    }
+
+    Ok(NonceResponse {
+        nonce: nonce.value().to_string(),
+        address: wallet_address.into(),
+        expires_at,
+    })
+}
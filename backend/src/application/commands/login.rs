@@ -1,6 +1,34 @@
+use std::sync::Arc;
+
+use crate::application::dtos::AuthTokenResponse;
+use crate::domain::entities::session::Session;
+use crate::domain::repositories::SessionRepository;
+use crate::domain::value_objects::WalletAddress;
 use crate::infrastructure::jwt::JwtManager;
+use crate::infrastructure::refresh_token::RefreshTokenManager;
+
+/// Opens a session for the address, which has just proven it controls the wallet.
+pub async fn login(
+    session_repository: Arc<dyn SessionRepository>,
+    jwt_manager: Arc<JwtManager>,
+    refresh_token_manager: Arc<RefreshTokenManager>,
+    address: String,
+) -> Result<AuthTokenResponse, String> {
+    let refresh_token = refresh_token_manager.generate_token();
+    let session = Session::new(
+        WalletAddress(address.clone()),
+        refresh_token_manager.hash_token(&refresh_token),
+        refresh_token_manager.expires_at(),
+    );
+    session_repository
+        .create(&session)
+        .await
+        .map_err(|e| format!("Failed to open session: {}", e))?;
 
-pub async fn login(address: String) -> Result<String, String> {
-    let jwt_manager = JwtManager::new();
-    jwt_manager.generate_token(&address)
+    Ok(AuthTokenResponse {
+        token: jwt_manager.generate_token(&address, session.id)?,
+        expires_in: jwt_manager.expiration(),
+        refresh_token,
+        address,
+    })
 }
@@ -0,0 +1,26 @@
+use std::sync::Arc;
+
+use crate::domain::repositories::SessionRepository;
+use crate::infrastructure::refresh_token::RefreshTokenManager;
+
+/// Ends the session of the refresh token, which also stops its access tokens from being accepted.
+/// Unknown refresh tokens are ignored, so that logging out twice is harmless.
+pub async fn logout(
+    session_repository: Arc<dyn SessionRepository>,
+    refresh_token_manager: Arc<RefreshTokenManager>,
+    refresh_token: String,
+) -> Result<(), String> {
+    let hash = refresh_token_manager.hash_token(&refresh_token);
+    let session = session_repository
+        .find_by_refresh_token_hash(&hash)
+        .await
+        .map_err(|e| format!("Failed to log out: {}", e))?;
+
+    if let Some(session) = session {
+        session_repository
+            .revoke(session.id)
+            .await
+            .map_err(|e| format!("Failed to log out: {}", e))?;
+    }
+    Ok(())
+}
@@ -1,3 +1,7 @@
 pub mod create_profile;
+pub mod issue_login_nonce;
 pub mod login;
+pub mod logout;
+pub mod refresh_session;
+pub mod revoke_sessions;
 pub mod update_profile;
@@ -0,0 +1,57 @@
+use std::sync::Arc;
+
+use crate::application::dtos::AuthTokenResponse;
+use crate::domain::repositories::SessionRepository;
+use crate::infrastructure::jwt::JwtManager;
+use crate::infrastructure::refresh_token::RefreshTokenManager;
+
+/// Exchanges a refresh token for a new access token and a new refresh token. Presenting a
+/// refresh token that was already exchanged means it leaked, so the session is revoked.
+pub async fn refresh_session(
+    session_repository: Arc<dyn SessionRepository>,
+    jwt_manager: Arc<JwtManager>,
+    refresh_token_manager: Arc<RefreshTokenManager>,
+    refresh_token: String,
+) -> Result<AuthTokenResponse, String> {
+    let hash = refresh_token_manager.hash_token(&refresh_token);
+    let session = session_repository
+        .find_by_refresh_token_hash(&hash)
+        .await
+        .map_err(|e| format!("Failed to refresh session: {}", e))?
+        .ok_or("Invalid refresh token")?;
+
+    if session.refresh_token_hash != hash {
+        session_repository
+            .revoke(session.id)
+            .await
+            .map_err(|e| format!("Failed to revoke session: {}", e))?;
+        tracing::warn!(
+            "Revoked session {} of {} after its refresh token was reused",
+            session.id,
+            session.address
+        );
+        return Err("Invalid refresh token".to_string());
+    }
+
+    let new_refresh_token = refresh_token_manager.generate_token();
+    let rotated = session.is_active()
+        && session_repository
+            .rotate_refresh_token(
+                session.id,
+                &hash,
+                &refresh_token_manager.hash_token(&new_refresh_token),
+                refresh_token_manager.expires_at(),
+            )
+            .await
+            .map_err(|e| format!("Failed to refresh session: {}", e))?;
+    if !rotated {
+        return Err("Session expired or revoked".to_string());
+    }
+
+    Ok(AuthTokenResponse {
+        token: jwt_manager.generate_token(session.address.as_str(), session.id)?,
+        expires_in: jwt_manager.expiration(),
+        refresh_token: new_refresh_token,
+        address: session.address.into(),
+    })
+}
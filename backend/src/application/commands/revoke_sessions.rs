@@ -0,0 +1,22 @@
+use std::sync::Arc;
+
+use crate::application::dtos::RevokeSessionsResponse;
+use crate::domain::repositories::SessionRepository;
+use crate::domain::value_objects::WalletAddress;
+
+/// Ends every session of the address, e.g. after one of its tokens leaked.
+pub async fn revoke_sessions(
+    session_repository: Arc<dyn SessionRepository>,
+    address: String,
+) -> Result<RevokeSessionsResponse, String> {
+    let wallet_address = WalletAddress::new(address)?;
+    let revoked = session_repository
+        .revoke_all_by_address(&wallet_address)
+        .await
+        .map_err(|e| format!("Failed to revoke sessions: {}", e))?;
+
+    Ok(RevokeSessionsResponse {
+        address: wallet_address.into(),
+        revoked,
+    })
+}
@@ -1,3 +1,4 @@
+use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
 
 #[derive(Debug, Serialize, Deserialize)]
@@ -15,12 +16,29 @@ pub struct VerifyMessageResponse {
 
 #[derive(Debug, Serialize, Deserialize)]
 pub struct NonceResponse {
-    pub nonce: i64,
+    pub nonce: String,
     pub address: String,
+    pub expires_at: DateTime<Utc>,
 }
 
+/// A short-lived access token, and the refresh token exchanging it for a new pair until the
+/// session ends.
 #[derive(Debug, Serialize, Deserialize)]
 pub struct AuthTokenResponse {
     pub token: String,
+    /// Lifetime of the access token, in seconds.
+    pub expires_in: usize,
+    pub refresh_token: String,
+    pub address: String,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct RefreshTokenRequest {
+    pub refresh_token: String,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct RevokeSessionsResponse {
     pub address: String,
+    pub revoked: u64,
 }
@@ -1,3 +1,2 @@
 pub mod get_all_profiles;
-pub mod get_login_nonce;
 pub mod get_profile;
@@ -1,3 +1,5 @@
 pub mod profile;
+pub mod session;
 
 pub use profile::Profile;
+pub use session::Session;
@@ -0,0 +1,39 @@
+use chrono::{DateTime, Utc};
+use uuid::Uuid;
+
+use crate::domain::value_objects::WalletAddress;
+
+/// A signed-in session of a wallet, kept alive by rotating its refresh token.
+#[derive(Debug, Clone)]
+pub struct Session {
+    pub id: Uuid,
+    pub address: WalletAddress,
+    pub refresh_token_hash: String,
+    /// Hash of the refresh token the current one replaced, whose reuse betrays a stolen token.
+    pub previous_refresh_token_hash: Option<String>,
+    pub expires_at: DateTime<Utc>,
+    pub revoked_at: Option<DateTime<Utc>>,
+    pub created_at: DateTime<Utc>,
+}
+
+impl Session {
+    pub fn new(
+        address: WalletAddress,
+        refresh_token_hash: String,
+        expires_at: DateTime<Utc>,
+    ) -> Self {
+        Self {
+            id: Uuid::new_v4(),
+            address,
+            refresh_token_hash,
+            previous_refresh_token_hash: None,
+            expires_at,
+            revoked_at: None,
+            created_at: Utc::now(),
+        }
+    }
+
+    pub fn is_active(&self) -> bool {
+        self.revoked_at.is_none() && self.expires_at > Utc::now()
+    }
+}
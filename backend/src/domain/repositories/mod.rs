@@ -1,3 +1,7 @@
+pub mod nonce_repository;
 pub mod profile_repository;
+pub mod session_repository;
 
+pub use nonce_repository::NonceRepository;
 pub use profile_repository::ProfileRepository;
+pub use session_repository::SessionRepository;
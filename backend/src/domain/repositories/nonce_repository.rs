@@ -0,0 +1,25 @@
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+
+use crate::domain::value_objects::{Nonce, WalletAddress};
+
+#[async_trait]
+pub trait NonceRepository: Send + Sync {
+    /// Saves the nonce unless the address already holds `max_outstanding` nonces that have not
+    /// expired, returning whether it did.
+    async fn save(
+        &self,
+        address: &WalletAddress,
+        nonce: &Nonce,
+        expires_at: DateTime<Utc>,
+        max_outstanding: i64,
+    ) -> Result<bool, Box<dyn std::error::Error>>;
+    /// Deletes the nonce if it was issued to the address and has not expired yet, returning
+    /// whether it did, so that a nonce is accepted at most once.
+    async fn consume(
+        &self,
+        address: &WalletAddress,
+        nonce: &Nonce,
+    ) -> Result<bool, Box<dyn std::error::Error>>;
+    async fn delete_expired(&self) -> Result<(), Box<dyn std::error::Error>>;
+}
@@ -0,0 +1,32 @@
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use uuid::Uuid;
+
+use crate::domain::{entities::session::Session, value_objects::WalletAddress};
+
+#[async_trait]
+pub trait SessionRepository: Send + Sync {
+    async fn create(&self, session: &Session) -> Result<(), Box<dyn std::error::Error>>;
+    async fn find_by_id(&self, id: Uuid) -> Result<Option<Session>, Box<dyn std::error::Error>>;
+    /// Finds the session whose current or previous refresh token has the hash.
+    async fn find_by_refresh_token_hash(
+        &self,
+        hash: &str,
+    ) -> Result<Option<Session>, Box<dyn std::error::Error>>;
+    /// Replaces the refresh token of the session and extends it if its refresh token still is
+    /// `current_hash`, returning whether it did, so that a refresh token is exchanged at most
+    /// once.
+    async fn rotate_refresh_token(
+        &self,
+        id: Uuid,
+        current_hash: &str,
+        new_hash: &str,
+        expires_at: DateTime<Utc>,
+    ) -> Result<bool, Box<dyn std::error::Error>>;
+    async fn revoke(&self, id: Uuid) -> Result<(), Box<dyn std::error::Error>>;
+    /// Revokes every active session of the address, returning how many there were.
+    async fn revoke_all_by_address(
+        &self,
+        address: &WalletAddress,
+    ) -> Result<u64, Box<dyn std::error::Error>>;
+}
@@ -2,10 +2,12 @@ use async_trait::async_trait;
 
 use crate::domain::value_objects::wallet_address::WalletAddress;
 
+/// A signed-in request: the EIP-4361 message the wallet signed and the address it claims to be
+/// signed by.
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub struct AuthChallenge {
-    pub nonce: i64,
     pub address: String,
+    pub message: String,
 }
 
 #[derive(Debug, Clone, PartialEq, Eq)]
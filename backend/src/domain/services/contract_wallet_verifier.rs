@@ -0,0 +1,17 @@
+use async_trait::async_trait;
+
+use crate::domain::value_objects::wallet_address::WalletAddress;
+
+/// Checks signatures of smart contract wallets, which cannot be recovered to the wallet address
+/// like those of externally owned accounts.
+#[async_trait]
+pub trait ContractWalletVerifier: Send + Sync {
+    /// Whether the wallet at `address` accepts `signature` for `hash` (EIP-1271), deploying the
+    /// wallet first when the signature is wrapped for a counterfactual wallet (EIP-6492).
+    async fn is_valid_signature(
+        &self,
+        address: &WalletAddress,
+        hash: [u8; 32],
+        signature: &[u8],
+    ) -> Result<bool, Box<dyn std::error::Error>>;
+}
@@ -1 +1,2 @@
 pub mod auth_service;
+pub mod contract_wallet_verifier;
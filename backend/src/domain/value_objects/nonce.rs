@@ -1,6 +1,8 @@
 use serde::{Deserialize, Serialize};
 use uuid::Uuid;
 
+/// Random single-use token a sign-in message must carry. EIP-4361 only allows alphanumeric
+/// nonces, so the UUID is kept without hyphens.
 #[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
 pub struct Nonce {
     value: String,
@@ -9,7 +11,7 @@ pub struct Nonce {
 impl Nonce {
     pub fn new() -> Self {
         Self {
-            value: Uuid::new_v4().to_string(),
+            value: Uuid::new_v4().simple().to_string(),
         }
     }
 
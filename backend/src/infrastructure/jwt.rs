@@ -1,52 +1,200 @@
-use jsonwebtoken::{decode, encode, DecodingKey, EncodingKey, Header, Validation};
+use base64ct::{Base64UrlUnpadded, Encoding};
+use jsonwebtoken::jwk::{
+    AlgorithmParameters, CommonParameters, EllipticCurve, Jwk, JwkSet, KeyAlgorithm,
+    OctetKeyPairParameters, OctetKeyPairType, PublicKeyUse, RSAKeyParameters, RSAKeyType,
+};
+use jsonwebtoken::{
+    decode, decode_header, encode, Algorithm, DecodingKey, EncodingKey, Header, Validation,
+};
+use rsa::pkcs8::spki::{ObjectIdentifier, SubjectPublicKeyInfoRef};
+use rsa::pkcs8::{DecodePublicKey, Document};
+use rsa::traits::PublicKeyParts;
+use rsa::RsaPublicKey;
 use serde::{Deserialize, Serialize};
-use std::env;
+use std::collections::HashMap;
+use std::path::{Path, PathBuf};
+use std::{env, fs};
+use uuid::Uuid;
+
+const ED25519_OID: ObjectIdentifier = ObjectIdentifier::new_unwrap("1.3.101.112");
 
 #[derive(Debug, Serialize, Deserialize, Clone)]
 pub struct JwtClaims {
+    /// Wallet address the token was issued to.
+    pub sub: String,
     pub address: String,
+    /// Session the token was issued for, which must still be active for the token to be.
+    pub sid: Uuid,
+    pub iss: String,
+    pub aud: String,
+    pub iat: usize,
     pub exp: usize,
 }
 
+/// How tokens are signed: with a secret shared by every service verifying them, or with private
+/// keys whose public halves are published so that other services verify tokens on their own.
+#[derive(Debug, Clone)]
+pub enum JwtKeys {
+    Secret(String),
+    KeyFiles {
+        /// `RS256` or `EdDSA`.
+        algorithm: Algorithm,
+        /// PEM private key tokens are signed with.
+        private_key_file: PathBuf,
+        /// Id of the signing key, which must be one of the verification keys.
+        key_id: String,
+        /// PEM public keys tokens are verified with, each named `<key id>.pem`. Keeping the
+        /// previous key there after a rotation keeps the tokens it signed valid until they expire.
+        public_keys_dir: PathBuf,
+    },
+}
+
+#[derive(Debug, Clone)]
+pub struct JwtConfig {
+    pub keys: JwtKeys,
+    pub issuer: String,
+    pub audience: String,
+    /// Lifetime of the access tokens, in seconds.
+    pub expiration: usize,
+}
+
+impl JwtConfig {
+    pub fn from_env() -> Self {
+        let algorithm = env::var("JWT_ALGORITHM").unwrap_or_else(|_| "HS256".to_string());
+        let keys = match algorithm.as_str() {
+            "HS256" => JwtKeys::Secret(env::var("JWT_SECRET").expect("JWT_SECRET must be set")),
+            "RS256" | "EdDSA" => JwtKeys::KeyFiles {
+                algorithm: algorithm.parse().unwrap(),
+                private_key_file: env::var("JWT_PRIVATE_KEY_FILE")
+                    .expect("JWT_PRIVATE_KEY_FILE must be set")
+                    .into(),
+                key_id: env::var("JWT_KEY_ID").expect("JWT_KEY_ID must be set"),
+                public_keys_dir: env::var("JWT_PUBLIC_KEYS_DIR")
+                    .expect("JWT_PUBLIC_KEYS_DIR must be set")
+                    .into(),
+            },
+            _ => panic!("JWT_ALGORITHM must be HS256, RS256 or EdDSA"),
+        };
+        let expiration: usize = env::var("JWT_EXPIRATION")
+            .unwrap_or_else(|_| "900".to_string())
+            .parse()
+            .expect("JWT_EXPIRATION must be a valid number (seconds)");
+
+        JwtConfig {
+            keys,
+            issuer: env::var("JWT_ISSUER").unwrap_or_else(|_| "guild-backend".to_string()),
+            audience: env::var("JWT_AUDIENCE").unwrap_or_else(|_| "guild".to_string()),
+            expiration,
+        }
+    }
+}
+
 pub struct JwtManager {
-    secret: String,
+    algorithm: Algorithm,
+    key_id: Option<String>,
+    encoding_key: EncodingKey,
+    /// Keys by the id tokens name in their `kid` header, none for a shared secret.
+    decoding_keys: HashMap<Option<String>, DecodingKey>,
+    jwks: JwkSet,
+    issuer: String,
+    audience: String,
     expiration: usize,
 }
 
 impl JwtManager {
     pub fn new() -> Self {
-        let secret = env::var("JWT_SECRET").expect("JWT_SECRET must be set");
-        let expiration: usize = env::var("JWT_EXPIRATION")
-            .unwrap_or_else(|_| "86400".to_string())
-            .parse()
-            .expect("JWT_EXPIRATION must be a valid number (seconds)");
+        Self::with_config(JwtConfig::from_env()).unwrap_or_else(|e| panic!("{}", e))
+    }
 
-        JwtManager { secret, expiration }
+    pub fn with_config(config: JwtConfig) -> Result<Self, String> {
+        let (algorithm, key_id, encoding_key, decoding_keys, jwks) = match config.keys {
+            JwtKeys::Secret(secret) => (
+                Algorithm::HS256,
+                None,
+                EncodingKey::from_secret(secret.as_bytes()),
+                HashMap::from([(None, DecodingKey::from_secret(secret.as_bytes()))]),
+                JwkSet { keys: vec![] },
+            ),
+            JwtKeys::KeyFiles {
+                algorithm,
+                private_key_file,
+                key_id,
+                public_keys_dir,
+            } => {
+                let encoding_key = load_private_key(algorithm, &private_key_file)?;
+                let mut decoding_keys = HashMap::new();
+                let mut jwks = JwkSet { keys: vec![] };
+                for (kid, path) in list_public_keys(&public_keys_dir)? {
+                    let (decoding_key, jwk) = load_public_key(algorithm, &kid, &path)?;
+                    decoding_keys.insert(Some(kid), decoding_key);
+                    jwks.keys.push(jwk);
+                }
+                if !decoding_keys.contains_key(&Some(key_id.clone())) {
+                    return Err(format!(
+                        "No public key {}.pem in {} for the signing key",
+                        key_id,
+                        public_keys_dir.display()
+                    ));
+                }
+                (algorithm, Some(key_id), encoding_key, decoding_keys, jwks)
+            }
+        };
+
+        Ok(JwtManager {
+            algorithm,
+            key_id,
+            encoding_key,
+            decoding_keys,
+            jwks,
+            issuer: config.issuer,
+            audience: config.audience,
+            expiration: config.expiration,
+        })
+    }
+
+    /// Lifetime of the access tokens, in seconds.
+    pub fn expiration(&self) -> usize {
+        self.expiration
+    }
+
+    /// Public keys tokens are verified with, empty when they are signed with a shared secret.
+    pub fn jwks(&self) -> &JwkSet {
+        &self.jwks
     }
 
-    pub fn generate_token(&self, address: &str) -> Result<String, String> {
+    pub fn generate_token(&self, address: &str, session_id: Uuid) -> Result<String, String> {
         let now = chrono::Utc::now().timestamp() as usize;
         let claims = JwtClaims {
+            sub: address.to_string(),
             address: address.to_string(),
+            sid: session_id,
+            iss: self.issuer.clone(),
+            aud: self.audience.clone(),
+            iat: now,
             exp: now + self.expiration,
         };
+        let mut header = Header::new(self.algorithm);
+        header.kid = self.key_id.clone();
 
-        encode(
-            &Header::default(),
-            &claims,
-            &EncodingKey::from_secret(self.secret.as_bytes()),
-        )
-        .map_err(|e| format!("Failed to generate token: {}", e))
+        encode(&header, &claims, &self.encoding_key)
+            .map_err(|e| format!("Failed to generate token: {}", e))
     }
 
     pub fn validate_token(&self, token: &str) -> Result<JwtClaims, String> {
-        decode::<JwtClaims>(
-            token,
-            &DecodingKey::from_secret(self.secret.as_bytes()),
-            &Validation::default(),
-        )
-        .map(|data| data.claims)
-        .map_err(|e| format!("Invalid token: {}", e))
+        let header = decode_header(token).map_err(|e| format!("Invalid token: {}", e))?;
+        let decoding_key = self
+            .decoding_keys
+            .get(&header.kid)
+            .ok_or("Invalid token: unknown key id")?;
+
+        let mut validation = Validation::new(self.algorithm);
+        validation.set_issuer(&[&self.issuer]);
+        validation.set_audience(&[&self.audience]);
+        validation.set_required_spec_claims(&["exp", "iss", "aud", "sub"]);
+
+        decode::<JwtClaims>(token, decoding_key, &validation)
+            .map(|data| data.claims)
+            .map_err(|e| format!("Invalid token: {}", e))
     }
 }
 
@@ -55,3 +203,86 @@ impl Default for JwtManager {
         Self::new()
     }
 }
+
+fn load_private_key(algorithm: Algorithm, path: &Path) -> Result<EncodingKey, String> {
+    let pem = fs::read(path)
+        .map_err(|e| format!("Could not read JWT private key {}: {}", path.display(), e))?;
+    match algorithm {
+        Algorithm::RS256 => EncodingKey::from_rsa_pem(&pem),
+        _ => EncodingKey::from_ed_pem(&pem),
+    }
+    .map_err(|e| format!("Invalid JWT private key {}: {}", path.display(), e))
+}
+
+/// Lists the `<key id>.pem` files of the directory, sorted by key id.
+fn list_public_keys(dir: &Path) -> Result<Vec<(String, PathBuf)>, String> {
+    let entries = fs::read_dir(dir)
+        .map_err(|e| format!("Could not read JWT public keys {}: {}", dir.display(), e))?;
+    let mut keys = Vec::new();
+    for entry in entries {
+        let path = entry
+            .map_err(|e| format!("Could not read JWT public keys {}: {}", dir.display(), e))?
+            .path();
+        if path.extension().is_some_and(|ext| ext == "pem") {
+            if let Some(kid) = path.file_stem().and_then(|stem| stem.to_str()) {
+                keys.push((kid.to_string(), path.clone()));
+            }
+        }
+    }
+    keys.sort();
+    Ok(keys)
+}
+
+/// Reads a PEM public key, both as a key to verify tokens with and as the JWK publishing it.
+fn load_public_key(
+    algorithm: Algorithm,
+    kid: &str,
+    path: &Path,
+) -> Result<(DecodingKey, Jwk), String> {
+    let invalid =
+        |e: &dyn std::fmt::Display| format!("Invalid JWT public key {}: {}", path.display(), e);
+    let pem = fs::read_to_string(path)
+        .map_err(|e| format!("Could not read JWT public key {}: {}", path.display(), e))?;
+
+    let (decoding_key, key_algorithm, parameters) = match algorithm {
+        Algorithm::RS256 => {
+            let key = RsaPublicKey::from_public_key_pem(&pem).map_err(|e| invalid(&e))?;
+            let n = Base64UrlUnpadded::encode_string(&key.n().to_bytes_be());
+            let e = Base64UrlUnpadded::encode_string(&key.e().to_bytes_be());
+            let decoding_key = DecodingKey::from_rsa_components(&n, &e).map_err(|e| invalid(&e))?;
+            let parameters = AlgorithmParameters::RSA(RSAKeyParameters {
+                key_type: RSAKeyType::RSA,
+                n,
+                e,
+            });
+            (decoding_key, KeyAlgorithm::RS256, parameters)
+        }
+        _ => {
+            let (_, document) = Document::from_pem(&pem).map_err(|e| invalid(&e))?;
+            let info =
+                SubjectPublicKeyInfoRef::try_from(document.as_bytes()).map_err(|e| invalid(&e))?;
+            if info.algorithm.oid != ED25519_OID {
+                return Err(invalid(&"not an Ed25519 key"));
+            }
+            let x = Base64UrlUnpadded::encode_string(info.subject_public_key.raw_bytes());
+            let decoding_key = DecodingKey::from_ed_components(&x).map_err(|e| invalid(&e))?;
+            let parameters = AlgorithmParameters::OctetKeyPair(OctetKeyPairParameters {
+                key_type: OctetKeyPairType::OctetKeyPair,
+                curve: EllipticCurve::Ed25519,
+                x,
+            });
+            (decoding_key, KeyAlgorithm::EdDSA, parameters)
+        }
+    };
+
+    let jwk = Jwk {
+        common: CommonParameters {
+            public_key_use: Some(PublicKeyUse::Signature),
+            key_algorithm: Some(key_algorithm),
+            key_id: Some(kid.to_string()),
+            ..Default::default()
+        },
+        algorithm: parameters,
+    };
+    Ok((decoding_key, jwk))
+}
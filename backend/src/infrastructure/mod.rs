@@ -1,3 +1,4 @@
 pub mod jwt;
+pub mod refresh_token;
 pub mod repositories;
 pub mod services;
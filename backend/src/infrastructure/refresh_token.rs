@@ -0,0 +1,51 @@
+use base64ct::{Base64UrlUnpadded, Encoding};
+use chrono::{DateTime, Duration, Utc};
+use ethers::core::rand::{thread_rng, RngCore};
+use sha3::{Digest, Sha3_256};
+use std::env;
+
+/// Issues the opaque refresh tokens of sessions. Only their hashes are stored, so that a leak of
+/// the database does not leak usable tokens.
+pub struct RefreshTokenManager {
+    expiration: i64,
+}
+
+impl RefreshTokenManager {
+    pub fn new() -> Self {
+        let expiration: i64 = env::var("REFRESH_TOKEN_EXPIRATION")
+            .unwrap_or_else(|_| "2592000".to_string())
+            .parse()
+            .expect("REFRESH_TOKEN_EXPIRATION must be a valid number (seconds)");
+
+        Self::with_expiration(expiration)
+    }
+
+    /// Sessions expire `expiration` seconds after their last refresh.
+    pub fn with_expiration(expiration: i64) -> Self {
+        RefreshTokenManager { expiration }
+    }
+
+    pub fn generate_token(&self) -> String {
+        let mut bytes = [0u8; 32];
+        thread_rng().fill_bytes(&mut bytes);
+        Base64UrlUnpadded::encode_string(&bytes)
+    }
+
+    pub fn hash_token(&self, token: &str) -> String {
+        Sha3_256::digest(token.as_bytes())
+            .iter()
+            .map(|byte| format!("{:02x}", byte))
+            .collect()
+    }
+
+    /// When a session opened now expires, unless refreshed in the meantime.
+    pub fn expires_at(&self) -> DateTime<Utc> {
+        Utc::now() + Duration::seconds(self.expiration)
+    }
+}
+
+impl Default for RefreshTokenManager {
+    fn default() -> Self {
+        Self::new()
+    }
+}
@@ -1,3 +1,7 @@
+pub mod postgres_nonce_repository;
 pub mod postgres_profile_repository;
+pub mod postgres_session_repository;
 
+pub use postgres_nonce_repository::PostgresNonceRepository;
 pub use postgres_profile_repository::PostgresProfileRepository;
+pub use postgres_session_repository::PostgresSessionRepository;
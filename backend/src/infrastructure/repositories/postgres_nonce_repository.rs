@@ -0,0 +1,101 @@
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use sqlx::PgPool;
+
+use crate::domain::repositories::nonce_repository::NonceRepository;
+use crate::domain::value_objects::{Nonce, WalletAddress};
+
+#[derive(Clone)]
+pub struct PostgresNonceRepository {
+    pool: PgPool,
+}
+
+impl PostgresNonceRepository {
+    pub fn new(pool: PgPool) -> Self {
+        Self { pool }
+    }
+}
+
+#[async_trait]
+impl NonceRepository for PostgresNonceRepository {
+    async fn save(
+        &self,
+        address: &WalletAddress,
+        nonce: &Nonce,
+        expires_at: DateTime<Utc>,
+        max_outstanding: i64,
+    ) -> Result<bool, Box<dyn std::error::Error>> {
+        let mut tx = self
+            .pool
+            .begin()
+            .await
+            .map_err(|e| Box::new(e) as Box<dyn std::error::Error>)?;
+
+        // Nonces of an address are counted and issued one request at a time
+        sqlx::query!(
+            "SELECT pg_advisory_xact_lock(hashtext(LOWER($1)))",
+            address.as_str()
+        )
+        .execute(&mut *tx)
+        .await
+        .map_err(|e| Box::new(e) as Box<dyn std::error::Error>)?;
+
+        let result = sqlx::query!(
+            r#"
+            INSERT INTO login_nonces (nonce, address, expires_at)
+            SELECT $1, $2::VARCHAR, $3
+            WHERE (
+                SELECT COUNT(*) FROM login_nonces
+                WHERE LOWER(address) = LOWER($2::VARCHAR) AND expires_at > NOW()
+            ) < $4
+            "#,
+            nonce.value(),
+            address.as_str(),
+            expires_at,
+            max_outstanding
+        )
+        .execute(&mut *tx)
+        .await
+        .map_err(|e| Box::new(e) as Box<dyn std::error::Error>)?;
+
+        tx.commit()
+            .await
+            .map_err(|e| Box::new(e) as Box<dyn std::error::Error>)?;
+
+        Ok(result.rows_affected() == 1)
+    }
+
+    async fn consume(
+        &self,
+        address: &WalletAddress,
+        nonce: &Nonce,
+    ) -> Result<bool, Box<dyn std::error::Error>> {
+        let result = sqlx::query!(
+            r#"
+            DELETE FROM login_nonces
+            WHERE nonce = $1 AND LOWER(address) = LOWER($2) AND expires_at > NOW()
+            "#,
+            nonce.value(),
+            address.as_str()
+        )
+        .execute(&self.pool)
+        .await
+        .map_err(|e| Box::new(e) as Box<dyn std::error::Error>)?;
+
+        Ok(result.rows_affected() == 1)
+    }
+
+    async fn delete_expired(&self) -> Result<(), Box<dyn std::error::Error>> {
+        sqlx::query!(
+            r#"
+            DELETE FROM login_nonces
+            WHERE expires_at <= NOW()
+            "#
+        )
+        .execute(&self.pool)
+        .await
+        .map_err(|e| Box::new(e) as Box<dyn std::error::Error>)?;
+
+        Ok(())
+    }
+}
@@ -0,0 +1,154 @@
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use sqlx::PgPool;
+use uuid::Uuid;
+
+use crate::domain::entities::session::Session;
+use crate::domain::repositories::session_repository::SessionRepository;
+use crate::domain::value_objects::WalletAddress;
+
+#[derive(Clone)]
+pub struct PostgresSessionRepository {
+    pool: PgPool,
+}
+
+impl PostgresSessionRepository {
+    pub fn new(pool: PgPool) -> Self {
+        Self { pool }
+    }
+}
+
+#[async_trait]
+impl SessionRepository for PostgresSessionRepository {
+    async fn create(&self, session: &Session) -> Result<(), Box<dyn std::error::Error>> {
+        sqlx::query!(
+            r#"
+            INSERT INTO auth_sessions (id, address, refresh_token_hash, previous_refresh_token_hash, expires_at, revoked_at, created_at)
+            VALUES ($1, $2, $3, $4, $5, $6, $7)
+            "#,
+            session.id,
+            session.address.as_str(),
+            session.refresh_token_hash,
+            session.previous_refresh_token_hash,
+            session.expires_at,
+            session.revoked_at,
+            session.created_at
+        )
+        .execute(&self.pool)
+        .await
+        .map_err(|e| Box::new(e) as Box<dyn std::error::Error>)?;
+
+        Ok(())
+    }
+
+    async fn find_by_id(&self, id: Uuid) -> Result<Option<Session>, Box<dyn std::error::Error>> {
+        let row = sqlx::query!(
+            r#"
+            SELECT id, address, refresh_token_hash, previous_refresh_token_hash, expires_at, revoked_at, created_at
+            FROM auth_sessions
+            WHERE id = $1
+            "#,
+            id
+        )
+        .fetch_optional(&self.pool)
+        .await
+        .map_err(|e| Box::new(e) as Box<dyn std::error::Error>)?;
+
+        Ok(row.map(|r| Session {
+            id: r.id,
+            address: WalletAddress(r.address),
+            refresh_token_hash: r.refresh_token_hash,
+            previous_refresh_token_hash: r.previous_refresh_token_hash,
+            expires_at: r.expires_at,
+            revoked_at: r.revoked_at,
+            created_at: r.created_at,
+        }))
+    }
+
+    async fn find_by_refresh_token_hash(
+        &self,
+        hash: &str,
+    ) -> Result<Option<Session>, Box<dyn std::error::Error>> {
+        let row = sqlx::query!(
+            r#"
+            SELECT id, address, refresh_token_hash, previous_refresh_token_hash, expires_at, revoked_at, created_at
+            FROM auth_sessions
+            WHERE refresh_token_hash = $1 OR previous_refresh_token_hash = $1
+            "#,
+            hash
+        )
+        .fetch_optional(&self.pool)
+        .await
+        .map_err(|e| Box::new(e) as Box<dyn std::error::Error>)?;
+
+        Ok(row.map(|r| Session {
+            id: r.id,
+            address: WalletAddress(r.address),
+            refresh_token_hash: r.refresh_token_hash,
+            previous_refresh_token_hash: r.previous_refresh_token_hash,
+            expires_at: r.expires_at,
+            revoked_at: r.revoked_at,
+            created_at: r.created_at,
+        }))
+    }
+
+    async fn rotate_refresh_token(
+        &self,
+        id: Uuid,
+        current_hash: &str,
+        new_hash: &str,
+        expires_at: DateTime<Utc>,
+    ) -> Result<bool, Box<dyn std::error::Error>> {
+        let result = sqlx::query!(
+            r#"
+            UPDATE auth_sessions
+            SET previous_refresh_token_hash = refresh_token_hash, refresh_token_hash = $3, expires_at = $4
+            WHERE id = $1 AND refresh_token_hash = $2 AND revoked_at IS NULL AND expires_at > NOW()
+            "#,
+            id,
+            current_hash,
+            new_hash,
+            expires_at
+        )
+        .execute(&self.pool)
+        .await
+        .map_err(|e| Box::new(e) as Box<dyn std::error::Error>)?;
+
+        Ok(result.rows_affected() == 1)
+    }
+
+    async fn revoke(&self, id: Uuid) -> Result<(), Box<dyn std::error::Error>> {
+        sqlx::query!(
+            r#"
+            UPDATE auth_sessions
+            SET revoked_at = NOW()
+            WHERE id = $1 AND revoked_at IS NULL
+            "#,
+            id
+        )
+        .execute(&self.pool)
+        .await
+        .map_err(|e| Box::new(e) as Box<dyn std::error::Error>)?;
+
+        Ok(())
+    }
+
+    async fn revoke_all_by_address(
+        &self,
+        address: &WalletAddress,
+    ) -> Result<u64, Box<dyn std::error::Error>> {
+        let result = sqlx::query!(
+            r#"
+            UPDATE auth_sessions
+            SET revoked_at = NOW()
+            WHERE LOWER(address) = LOWER($1) AND revoked_at IS NULL AND expires_at > NOW()
+            "#,
+            address.as_str()
+        )
+        .execute(&self.pool)
+        .await
+        .map_err(|e| Box::new(e) as Box<dyn std::error::Error>)?;
+
+        Ok(result.rows_affected())
+    }
+}
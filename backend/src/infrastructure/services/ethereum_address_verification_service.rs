@@ -1,20 +1,85 @@
+use anyhow::Context;
 use async_trait::async_trait;
-use ethers::core::utils::hash_message;
-use ethers::types::{Address, Signature};
+use ethers::types::{Address, Bytes};
+use siwe::{Message, VerificationError, VerificationOpts};
+use std::env;
 use std::str::FromStr;
 use std::sync::Arc;
+use time::{Duration, OffsetDateTime};
 
-use crate::domain::repositories::ProfileRepository;
+use crate::domain::repositories::NonceRepository;
 use crate::domain::services::auth_service::{AuthChallenge, AuthResult, AuthService};
-use crate::domain::value_objects::WalletAddress;
+use crate::domain::services::contract_wallet_verifier::ContractWalletVerifier;
+use crate::domain::value_objects::{Nonce, WalletAddress};
 
+/// How far in the future a message may be issued, to absorb clock drift between the wallet and
+/// the server.
+const CLOCK_SKEW: Duration = Duration::seconds(60);
+
+/// What a sign-in message must be bound to for this server to accept it.
+#[derive(Debug, Clone)]
+pub struct SiweConfig {
+    /// Authority the frontend is served from, e.g. `theguild.dev` or `localhost:4321`.
+    pub domain: String,
+    /// Chain the message must name, any chain when unset.
+    pub chain_id: Option<u64>,
+    /// How long after its issuance a message is accepted, even without an expiration time.
+    pub max_age: Duration,
+}
+
+impl SiweConfig {
+    pub fn from_env() -> anyhow::Result<Self> {
+        let domain = env::var("SIWE_DOMAIN").context("SIWE_DOMAIN must be set")?;
+        let chain_id = env::var("SIWE_CHAIN_ID")
+            .ok()
+            .map(|chain_id| chain_id.parse())
+            .transpose()
+            .context("SIWE_CHAIN_ID must be a valid chain id")?;
+        let max_age: i64 = env::var("SIWE_MAX_AGE")
+            .unwrap_or_else(|_| "600".to_string())
+            .parse()
+            .context("SIWE_MAX_AGE must be a valid number (seconds)")?;
+
+        Ok(SiweConfig {
+            domain,
+            chain_id,
+            max_age: Duration::seconds(max_age),
+        })
+    }
+}
+
+/// Verifies EIP-4361 (Sign-In with Ethereum) messages signed by externally owned accounts, or by
+/// smart contract wallets when a contract wallet verifier is given.
 pub struct EthereumAddressVerificationService {
-    profile_repository: Arc<dyn ProfileRepository>,
+    nonce_repository: Arc<dyn NonceRepository>,
+    contract_wallet_verifier: Option<Arc<dyn ContractWalletVerifier>>,
+    config: SiweConfig,
 }
 
 impl EthereumAddressVerificationService {
-    pub fn new(profile_repository: Arc<dyn ProfileRepository>) -> Self {
-        Self { profile_repository }
+    pub fn new(
+        nonce_repository: Arc<dyn NonceRepository>,
+        contract_wallet_verifier: Option<Arc<dyn ContractWalletVerifier>>,
+        config: SiweConfig,
+    ) -> Self {
+        Self {
+            nonce_repository,
+            contract_wallet_verifier,
+            config,
+        }
+    }
+
+    /// Checks what the `siwe` crate leaves to the relying party: the signer and chain the
+    /// message names, and how long ago it was issued.
+    fn is_bound_to_request(&self, message: &Message, address: Address) -> bool {
+        let now = OffsetDateTime::now_utc();
+        Address::from(message.address) == address
+            && self
+                .config
+                .chain_id
+                .is_none_or(|chain_id| message.chain_id == chain_id)
+            && message.issued_at <= now + CLOCK_SKEW
+            && message.issued_at >= now - self.config.max_age
     }
 }
 
@@ -25,33 +90,59 @@ impl AuthService for EthereumAddressVerificationService {
         challenge: &AuthChallenge,
         signature: &str,
     ) -> Result<Option<AuthResult>, Box<dyn std::error::Error>> {
-        // Create the message with the nonce
-        let message = format!(
-            "Sign this message to authenticate with The Guild.\n\nNonce: {}",
-            challenge.nonce
-        );
-
-        // EIP-191 prefix + keccak256
-        let msg_hash = hash_message(message);
-
-        // Parse signature and expected address
-        let sig = Signature::from_str(signature)?;
+        let message = Message::from_str(&challenge.message)?;
+        let signature = Bytes::from_str(signature)?;
         let expected: Address = challenge.address.parse()?;
 
-        // Recover signer from signature
-        let recovered = sig.recover(msg_hash)?;
+        if !self.is_bound_to_request(&message, expected) {
+            return Ok(None);
+        }
 
-        if recovered == expected {
-            // Increment the nonce after successful verification
-            let wallet_address = WalletAddress(challenge.address.clone());
-            self.profile_repository
-                .increment_login_nonce(&wallet_address)
-                .await?;
+        // Checks the domain, expiration and not-before times, then recovers the signer.
+        let opts = VerificationOpts {
+            domain: Some(self.config.domain.parse()?),
+            ..Default::default()
+        };
+        let wallet_address = WalletAddress(challenge.address.clone());
+        let verified = match message.verify(&signature, &opts).await {
+            Ok(()) => true,
+            // Not signed by the key of the address, which may still be a smart contract wallet
+            Err(
+                VerificationError::Signer
+                | VerificationError::SignatureLength
+                | VerificationError::Crypto(_),
+            ) => match &self.contract_wallet_verifier {
+                Some(verifier) => {
+                    verifier
+                        .is_valid_signature(&wallet_address, message.eip191_hash()?, &signature)
+                        .await?
+                }
+                None => false,
+            },
+            Err(e) => {
+                tracing::debug!("Rejected sign-in message of {}: {}", challenge.address, e);
+                false
+            }
+        };
+        if !verified {
+            return Ok(None);
+        }
 
-            Ok(Some(AuthResult { wallet_address }))
-        } else {
-            Ok(None)
+        // Only then use up the nonce, which must have been issued to this address
+        let nonce = Nonce::from_string(message.nonce.clone());
+        if !self
+            .nonce_repository
+            .consume(&wallet_address, &nonce)
+            .await?
+        {
+            tracing::debug!(
+                "Rejected unknown, used or expired nonce of {}",
+                challenge.address
+            );
+            return Ok(None);
         }
+
+        Ok(Some(AuthResult { wallet_address }))
     }
 }
 
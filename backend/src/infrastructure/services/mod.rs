@@ -1 +1,2 @@
 pub mod ethereum_address_verification_service;
+pub mod rpc_contract_wallet_verifier;
@@ -0,0 +1,210 @@
+use async_trait::async_trait;
+use ethers::abi::{self, ParamType, Token};
+use ethers::providers::{Http, JsonRpcClient, Middleware, Provider, RpcError};
+use ethers::types::{Address, Bytes, TransactionRequest, U256};
+use std::env;
+
+use crate::domain::services::contract_wallet_verifier::ContractWalletVerifier;
+use crate::domain::value_objects::WalletAddress;
+
+/// Selector of `isValidSignature(bytes32,bytes)`, which is also the value returned by wallets
+/// accepting the signature.
+const ERC1271_MAGIC_VALUE: [u8; 4] = [0x16, 0x26, 0xba, 0x7e];
+
+/// Suffix of signatures wrapped for wallets that are not deployed yet.
+const ERC6492_MAGIC_SUFFIX: [u8; 32] = [
+    0x64, 0x92, 0x64, 0x92, 0x64, 0x92, 0x64, 0x92, 0x64, 0x92, 0x64, 0x92, 0x64, 0x92, 0x64, 0x92,
+    0x64, 0x92, 0x64, 0x92, 0x64, 0x92, 0x64, 0x92, 0x64, 0x92, 0x64, 0x92, 0x64, 0x92, 0x64, 0x92,
+];
+
+/// Asks the wallet contract itself, through a JSON-RPC node of the chain the wallets live on.
+pub struct RpcContractWalletVerifier<P = Http> {
+    provider: Provider<P>,
+}
+
+impl RpcContractWalletVerifier {
+    pub fn new(rpc_url: &str) -> Result<Self, Box<dyn std::error::Error>> {
+        Ok(Self::with_provider(Provider::<Http>::try_from(rpc_url)?))
+    }
+
+    /// Smart contract wallets can only sign in when `ETH_RPC_URL` is set.
+    pub fn from_env() -> Option<Self> {
+        env::var("ETH_RPC_URL")
+            .ok()
+            .map(|url| Self::new(&url).expect("ETH_RPC_URL must be a valid URL"))
+    }
+}
+
+impl<P: JsonRpcClient> RpcContractWalletVerifier<P> {
+    pub fn with_provider(provider: Provider<P>) -> Self {
+        Self { provider }
+    }
+
+    async fn call_is_valid_signature(
+        &self,
+        address: Address,
+        hash: [u8; 32],
+        signature: &[u8],
+    ) -> Result<bool, Box<dyn std::error::Error>> {
+        let tx = TransactionRequest::new()
+            .to(address)
+            .data(is_valid_signature_calldata(hash, signature));
+        match self.provider.call(&tx.into(), None).await {
+            Ok(return_data) => Ok(is_magic_value(&return_data)),
+            // The node answered, so the wallet reverted or does not implement EIP-1271
+            Err(e) if e.as_error_response().is_some() => Ok(false),
+            Err(e) => Err(e.into()),
+        }
+    }
+
+    /// Deploys the wallet through its factory then asks it about the signature, both from the
+    /// constructor of a contract sent through `eth_call`, so that nothing lands on chain.
+    async fn call_deployless_is_valid_signature(
+        &self,
+        address: Address,
+        factory: Address,
+        factory_calldata: &[u8],
+        hash: [u8; 32],
+        signature: &[u8],
+    ) -> Result<bool, Box<dyn std::error::Error>> {
+        let validator = deployless_validator(
+            factory,
+            factory_calldata,
+            address,
+            &is_valid_signature_calldata(hash, signature),
+        )?;
+        let tx = TransactionRequest::new().data(validator);
+        match self.provider.call(&tx.into(), None).await {
+            Ok(output) => Ok(output.len() == 64
+                && is_magic_value(&output[..32])
+                && U256::from_big_endian(&output[32..]) == U256::one()),
+            Err(e) if e.as_error_response().is_some() => Ok(false),
+            Err(e) => Err(e.into()),
+        }
+    }
+}
+
+#[async_trait]
+impl<P: JsonRpcClient + 'static> ContractWalletVerifier for RpcContractWalletVerifier<P> {
+    async fn is_valid_signature(
+        &self,
+        address: &WalletAddress,
+        hash: [u8; 32],
+        signature: &[u8],
+    ) -> Result<bool, Box<dyn std::error::Error>> {
+        let address: Address = address.as_str().parse()?;
+
+        let Some(wrapped) = signature.strip_suffix(&ERC6492_MAGIC_SUFFIX) else {
+            return self.call_is_valid_signature(address, hash, signature).await;
+        };
+        let tokens = abi::decode(
+            &[ParamType::Address, ParamType::Bytes, ParamType::Bytes],
+            wrapped,
+        )?;
+        let [Token::Address(factory), Token::Bytes(factory_calldata), Token::Bytes(signature)] =
+            tokens.as_slice()
+        else {
+            return Err("Invalid EIP-6492 signature".into());
+        };
+
+        // Once deployed, the wallet checks the original signature itself
+        if !self.provider.get_code(address, None).await?.is_empty() {
+            return self.call_is_valid_signature(address, hash, signature).await;
+        }
+        self.call_deployless_is_valid_signature(
+            address,
+            *factory,
+            factory_calldata,
+            hash,
+            signature,
+        )
+        .await
+    }
+}
+
+fn is_valid_signature_calldata(hash: [u8; 32], signature: &[u8]) -> Bytes {
+    let arguments = abi::encode(&[
+        Token::FixedBytes(hash.to_vec()),
+        Token::Bytes(signature.to_vec()),
+    ]);
+    [ERC1271_MAGIC_VALUE.as_slice(), &arguments].concat().into()
+}
+
+/// Size of the program in [`deployless_validator`], which the calldata it copies follows.
+const DEPLOYLESS_VALIDATOR_SIZE: u16 = 98;
+
+/// Creation code calling `factory` then `signer`, and returning the first 32 bytes `signer`
+/// returned followed by whether that call succeeded.
+///
+/// The result is read past both calldatas, from memory nothing wrote to, so that a call
+/// returning nothing is not mistaken for the magic value the calldata starts with.
+fn deployless_validator(
+    factory: Address,
+    factory_calldata: &[u8],
+    signer: Address,
+    validation_calldata: &[u8],
+) -> Result<Bytes, Box<dyn std::error::Error>> {
+    let factory_calldata_size = u16::try_from(factory_calldata.len())?;
+    let validation_calldata_size = u16::try_from(validation_calldata.len())?;
+    let validation_calldata_offset = DEPLOYLESS_VALIDATOR_SIZE
+        .checked_add(factory_calldata_size)
+        .ok_or("EIP-6492 signature too large")?;
+    let result = factory_calldata_size
+        .max(validation_calldata_size)
+        .checked_next_multiple_of(32)
+        .filter(|offset| *offset <= u16::MAX - 32)
+        .ok_or("EIP-6492 signature too large")?;
+
+    const PUSH1: u8 = 0x60;
+    const PUSH2: u8 = 0x61;
+    const PUSH20: u8 = 0x73;
+    const CODECOPY: u8 = 0x39;
+    const GAS: u8 = 0x5a;
+    const CALL: u8 = 0xf1;
+    const POP: u8 = 0x50;
+    const MSTORE: u8 = 0x52;
+    const RETURN: u8 = 0xf3;
+
+    let mut code = Vec::with_capacity(
+        DEPLOYLESS_VALIDATOR_SIZE as usize + factory_calldata.len() + validation_calldata.len(),
+    );
+    // Deploy the wallet, whether or not it works out
+    code.push(PUSH2);
+    code.extend(factory_calldata_size.to_be_bytes());
+    code.push(PUSH2);
+    code.extend(DEPLOYLESS_VALIDATOR_SIZE.to_be_bytes());
+    code.extend([PUSH1, 0, CODECOPY]);
+    code.extend([PUSH1, 0, PUSH1, 0, PUSH2]);
+    code.extend(factory_calldata_size.to_be_bytes());
+    code.extend([PUSH1, 0, PUSH1, 0, PUSH20]);
+    code.extend(factory.as_bytes());
+    code.extend([GAS, CALL, POP]);
+    // Ask it about the signature
+    code.push(PUSH2);
+    code.extend(validation_calldata_size.to_be_bytes());
+    code.push(PUSH2);
+    code.extend(validation_calldata_offset.to_be_bytes());
+    code.extend([PUSH1, 0, CODECOPY]);
+    code.extend([PUSH1, 32, PUSH2]);
+    code.extend(result.to_be_bytes());
+    code.push(PUSH2);
+    code.extend(validation_calldata_size.to_be_bytes());
+    code.extend([PUSH1, 0, PUSH1, 0, PUSH20]);
+    code.extend(signer.as_bytes());
+    code.extend([GAS, CALL]);
+    // Return what it said and whether it succeeded
+    code.push(PUSH2);
+    code.extend((result + 32).to_be_bytes());
+    code.extend([MSTORE, PUSH1, 64, PUSH2]);
+    code.extend(result.to_be_bytes());
+    code.push(RETURN);
+    debug_assert_eq!(code.len(), DEPLOYLESS_VALIDATOR_SIZE as usize);
+
+    code.extend(factory_calldata);
+    code.extend(validation_calldata);
+    Ok(code.into())
+}
+
+fn is_magic_value(return_data: &[u8]) -> bool {
+    return_data.get(..4) == Some(ERC1271_MAGIC_VALUE.as_slice())
+}
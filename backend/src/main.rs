@@ -1,5 +1,7 @@
+use domain::repositories::NonceRepository;
+use infrastructure::repositories::PostgresNonceRepository;
 use presentation::api::create_app;
-use std::{env, net::SocketAddr};
+use std::{env, net::SocketAddr, time::Duration};
 use tracing_subscriber::{layer::SubscriberExt, util::SubscriberInitExt};
 
 pub mod application;
@@ -32,7 +34,19 @@ async fn main() -> anyhow::Result<()> {
         sqlx::migrate!("./migrations").run(&pool).await?;
     }
 
-    let app = create_app(pool).await;
+    // Expired nonces are cleared in the background rather than while issuing them
+    let nonce_repository = PostgresNonceRepository::new(pool.clone());
+    tokio::spawn(async move {
+        let mut interval = tokio::time::interval(Duration::from_secs(60));
+        loop {
+            interval.tick().await;
+            if let Err(e) = nonce_repository.delete_expired().await {
+                tracing::warn!("Could not delete expired nonces: {}", e);
+            }
+        }
+    });
+
+    let app = create_app(pool).await?;
 
     let port = env::var("PORT").unwrap_or_else(|_| "3001".to_string());
     let addr = SocketAddr::from(([0, 0, 0, 0], port.parse::<u16>().unwrap()));
@@ -1,10 +1,16 @@
 use std::sync::Arc;
 
-use crate::domain::repositories::ProfileRepository;
+use crate::domain::repositories::{NonceRepository, ProfileRepository, SessionRepository};
 use crate::domain::services::auth_service::AuthService;
+use crate::domain::services::contract_wallet_verifier::ContractWalletVerifier;
 use crate::infrastructure::{
-    repositories::PostgresProfileRepository,
-    services::ethereum_address_verification_service::EthereumAddressVerificationService,
+    jwt::JwtManager,
+    refresh_token::RefreshTokenManager,
+    repositories::{PostgresNonceRepository, PostgresProfileRepository, PostgresSessionRepository},
+    services::{
+        ethereum_address_verification_service::{EthereumAddressVerificationService, SiweConfig},
+        rpc_contract_wallet_verifier::RpcContractWalletVerifier,
+    },
 };
 use axum::middleware::{from_fn, from_fn_with_state};
 use axum::{
@@ -21,43 +27,89 @@ use tower_http::{
 
 use super::handlers::{
     create_profile_handler, delete_profile_handler, get_all_profiles_handler, get_nonce_handler,
-    get_profile_handler, login_handler, update_profile_handler,
+    get_profile_handler, jwks_handler, login_handler, logout_handler, refresh_handler,
+    revoke_sessions_handler, update_profile_handler,
 };
 
-use super::middlewares::{eth_auth_layer, test_auth_layer};
+use super::middlewares::{admin_auth_layer, eth_auth_layer, siwe_auth_layer, test_auth_layer};
 
-pub async fn create_app(pool: sqlx::PgPool) -> Router {
-    let profile_repository = Arc::from(PostgresProfileRepository::new(pool));
-    let auth_service = EthereumAddressVerificationService::new(profile_repository.clone());
+pub async fn create_app(pool: sqlx::PgPool) -> anyhow::Result<Router> {
+    let profile_repository = Arc::from(PostgresProfileRepository::new(pool.clone()));
+    let nonce_repository = Arc::from(PostgresNonceRepository::new(pool.clone()));
+    let session_repository = Arc::from(PostgresSessionRepository::new(pool));
+    let contract_wallet_verifier = RpcContractWalletVerifier::from_env()
+        .map(|verifier| Arc::new(verifier) as Arc<dyn ContractWalletVerifier>);
+    let auth_service = EthereumAddressVerificationService::new(
+        nonce_repository.clone(),
+        contract_wallet_verifier,
+        SiweConfig::from_env()?,
+    );
 
     let state: AppState = AppState {
         profile_repository,
+        nonce_repository,
+        session_repository,
+        jwt_manager: Arc::new(JwtManager::new()),
+        refresh_token_manager: Arc::new(RefreshTokenManager::new()),
         auth_service: Arc::from(auth_service),
+        admin_token: std::env::var("ADMIN_TOKEN")
+            .ok()
+            .filter(|token| !token.is_empty()),
     };
 
+    Ok(api(state))
+}
+
+pub fn api(state: AppState) -> Router {
     let protected_routes = Router::new()
         .route("/profiles", post(create_profile_handler))
         .route("/profiles/", post(create_profile_handler))
         .route("/profiles/:address", put(update_profile_handler))
         .route("/profiles/:address", delete(delete_profile_handler))
+        .with_state(state.clone());
+
+    // Opening a session takes a signed message, so that an access token, stolen or not, cannot
+    // open a session that outlives its own
+    let login_routes = Router::new()
         .route("/auth/login", post(login_handler))
         .with_state(state.clone());
 
-    let protected_with_auth = if std::env::var("TEST_MODE").is_ok() {
-        protected_routes.layer(from_fn(test_auth_layer))
+    let (protected_with_auth, login_with_auth) = if std::env::var("TEST_MODE").is_ok() {
+        (
+            protected_routes.layer(from_fn(test_auth_layer)),
+            login_routes.layer(from_fn(test_auth_layer)),
+        )
     } else {
-        protected_routes.layer(from_fn_with_state(state.clone(), eth_auth_layer))
+        (
+            protected_routes.layer(from_fn_with_state(state.clone(), eth_auth_layer)),
+            login_routes.layer(from_fn_with_state(state.clone(), siwe_auth_layer)),
+        )
     };
 
     let public_routes = Router::new()
         .route("/profiles/:address", get(get_profile_handler))
         .route("/profiles", get(get_all_profiles_handler))
         .route("/auth/nonce/:address", get(get_nonce_handler))
+        .route("/auth/refresh", post(refresh_handler))
+        .route("/auth/logout", post(logout_handler))
+        .route("/.well-known/jwks.json", get(jwks_handler))
+        .with_state(state.clone());
+
+    // Admin routes are only served when an admin token is configured
+    let admin_routes = Router::new()
+        .route("/admin/sessions/:address", delete(revoke_sessions_handler))
+        .layer(from_fn_with_state(state.clone(), admin_auth_layer))
         .with_state(state.clone());
 
     Router::new()
         .merge(protected_with_auth)
+        .merge(login_with_auth)
         .merge(public_routes)
+        .merge(if state.admin_token.is_some() {
+            admin_routes
+        } else {
+            Router::new()
+        })
         .with_state(state.clone())
         .layer(
             ServiceBuilder::new()
@@ -75,7 +127,12 @@ pub async fn create_app(pool: sqlx::PgPool) -> Router {
 #[derive(Clone)]
 pub struct AppState {
     pub profile_repository: Arc<dyn ProfileRepository>,
+    pub nonce_repository: Arc<dyn NonceRepository>,
+    pub session_repository: Arc<dyn SessionRepository>,
+    pub jwt_manager: Arc<JwtManager>,
+    pub refresh_token_manager: Arc<RefreshTokenManager>,
     pub auth_service: Arc<dyn AuthService>,
+    pub admin_token: Option<String>,
 }
 
 pub fn test_api(state: AppState) -> Router {
@@ -91,11 +148,25 @@ pub fn test_api(state: AppState) -> Router {
         .route("/profiles/:address", get(get_profile_handler))
         .route("/profiles", get(get_all_profiles_handler))
         .route("/auth/nonce/:address", get(get_nonce_handler))
+        .route("/auth/refresh", post(refresh_handler))
+        .route("/auth/logout", post(logout_handler))
+        .route("/.well-known/jwks.json", get(jwks_handler))
+        .with_state(state.clone());
+
+    // Admin routes are only served when an admin token is configured
+    let admin_routes = Router::new()
+        .route("/admin/sessions/:address", delete(revoke_sessions_handler))
+        .layer(from_fn_with_state(state.clone(), admin_auth_layer))
         .with_state(state.clone());
 
     Router::new()
         .merge(protected_routes)
         .merge(public_routes)
+        .merge(if state.admin_token.is_some() {
+            admin_routes
+        } else {
+            Router::new()
+        })
         .with_state(state.clone())
         .layer(
             ServiceBuilder::new()
@@ -7,15 +7,13 @@ use axum::{
 
 use crate::{
     application::{
-        commands::{create_profile::create_profile, login::login, update_profile::update_profile},
-        dtos::{
-            AuthTokenResponse, CreateProfileRequest, NonceResponse, ProfileResponse,
-            UpdateProfileRequest,
-        },
-        queries::{
-            get_all_profiles::get_all_profiles, get_login_nonce::get_login_nonce,
-            get_profile::get_profile,
+        commands::{
+            create_profile::create_profile, issue_login_nonce::issue_login_nonce, login::login,
+            logout::logout, refresh_session::refresh_session, revoke_sessions::revoke_sessions,
+            update_profile::update_profile,
         },
+        dtos::{CreateProfileRequest, ProfileResponse, RefreshTokenRequest, UpdateProfileRequest},
+        queries::{get_all_profiles::get_all_profiles, get_profile::get_profile},
     },
     domain::value_objects::WalletAddress,
 };
@@ -85,17 +83,73 @@ pub async fn get_nonce_handler(
     State(state): State<AppState>,
     Path(address): Path<String>,
 ) -> impl IntoResponse {
-    match get_login_nonce(state.profile_repository, address.clone()).await {
-        Ok(nonce) => Json(NonceResponse { nonce, address }).into_response(),
-        Err(e) => (StatusCode::NOT_FOUND, Json(serde_json::json!({"error": e}))).into_response(),
+    match issue_login_nonce(state.nonce_repository, address).await {
+        Ok(nonce) => Json(nonce).into_response(),
+        Err(e) => {
+            let status = if e.starts_with("Too many nonces") {
+                StatusCode::TOO_MANY_REQUESTS
+            } else {
+                StatusCode::BAD_REQUEST
+            };
+            (status, Json(serde_json::json!({"error": e}))).into_response()
+        }
     }
 }
 
 pub async fn login_handler(
+    State(state): State<AppState>,
     Extension(VerifiedWallet(address)): Extension<VerifiedWallet>,
 ) -> impl IntoResponse {
-    match login(address.clone()).await {
-        Ok(token) => (StatusCode::OK, Json(AuthTokenResponse { token, address })).into_response(),
+    match login(
+        state.session_repository,
+        state.jwt_manager,
+        state.refresh_token_manager,
+        address,
+    )
+    .await
+    {
+        Ok(tokens) => (StatusCode::OK, Json(tokens)).into_response(),
+        Err(e) => (
+            StatusCode::INTERNAL_SERVER_ERROR,
+            Json(serde_json::json!({"error": e})),
+        )
+            .into_response(),
+    }
+}
+
+pub async fn refresh_handler(
+    State(state): State<AppState>,
+    Json(payload): Json<RefreshTokenRequest>,
+) -> impl IntoResponse {
+    match refresh_session(
+        state.session_repository,
+        state.jwt_manager,
+        state.refresh_token_manager,
+        payload.refresh_token,
+    )
+    .await
+    {
+        Ok(tokens) => (StatusCode::OK, Json(tokens)).into_response(),
+        Err(e) => (
+            StatusCode::UNAUTHORIZED,
+            Json(serde_json::json!({"error": e})),
+        )
+            .into_response(),
+    }
+}
+
+pub async fn logout_handler(
+    State(state): State<AppState>,
+    Json(payload): Json<RefreshTokenRequest>,
+) -> impl IntoResponse {
+    match logout(
+        state.session_repository,
+        state.refresh_token_manager,
+        payload.refresh_token,
+    )
+    .await
+    {
+        Ok(()) => StatusCode::NO_CONTENT.into_response(),
         Err(e) => (
             StatusCode::INTERNAL_SERVER_ERROR,
             Json(serde_json::json!({"error": e})),
@@ -103,3 +157,21 @@ pub async fn login_handler(
             .into_response(),
     }
 }
+
+pub async fn revoke_sessions_handler(
+    State(state): State<AppState>,
+    Path(address): Path<String>,
+) -> impl IntoResponse {
+    match revoke_sessions(state.session_repository, address).await {
+        Ok(revoked) => (StatusCode::OK, Json(revoked)).into_response(),
+        Err(e) => (
+            StatusCode::BAD_REQUEST,
+            Json(serde_json::json!({"error": e})),
+        )
+            .into_response(),
+    }
+}
+
+pub async fn jwks_handler(State(state): State<AppState>) -> impl IntoResponse {
+    Json(state.jwt_manager.jwks().clone())
+}
@@ -1,13 +1,16 @@
 use axum::{
     body::Body,
     extract::State,
-    http::{Request, StatusCode},
+    http::{HeaderMap, Request, StatusCode},
     middleware::Next,
     response::Response,
 };
 
+use base64ct::{Base64, Encoding};
+use ethers::utils::keccak256;
+use subtle::ConstantTimeEq;
+
 use crate::domain::services::auth_service::AuthChallenge;
-use crate::infrastructure::jwt::JwtManager;
 
 use super::api::AppState;
 
@@ -36,9 +39,17 @@ pub async fn eth_auth_layer(
     if let Some(auth_header) = headers.get("authorization") {
         if let Ok(auth_str) = auth_header.to_str() {
             if let Some(token) = auth_str.strip_prefix("Bearer ") {
-                let jwt_manager = JwtManager::new();
-                if let Ok(claims) = jwt_manager.validate_token(token) {
-                    req.extensions_mut().insert(VerifiedWallet(claims.address));
+                if let Ok(claims) = state.jwt_manager.validate_token(token) {
+                    // Tokens of sessions that were logged out or revoked are no longer accepted
+                    let session = state
+                        .session_repository
+                        .find_by_id(claims.sid)
+                        .await
+                        .map_err(|_| StatusCode::INTERNAL_SERVER_ERROR)?;
+                    if !session.is_some_and(|session| session.is_active()) {
+                        return Err(StatusCode::UNAUTHORIZED);
+                    }
+                    req.extensions_mut().insert(VerifiedWallet(claims.sub));
                     return Ok(next.run(req).await);
                 }
             }
@@ -46,6 +57,26 @@ pub async fn eth_auth_layer(
     }
 
     // Fall back to signature verification
+    let address = verify_siwe_headers(&state, headers).await?;
+    req.extensions_mut().insert(VerifiedWallet(address));
+
+    Ok(next.run(req).await)
+}
+
+/// Only lets through requests signed by the wallet itself, for the routes that must not be
+/// reachable with an access token, such as opening a new session.
+pub async fn siwe_auth_layer(
+    State(state): State<AppState>,
+    mut req: Request<Body>,
+    next: Next,
+) -> Result<Response, StatusCode> {
+    let address = verify_siwe_headers(&state, req.headers()).await?;
+    req.extensions_mut().insert(VerifiedWallet(address));
+
+    Ok(next.run(req).await)
+}
+
+async fn verify_siwe_headers(state: &AppState, headers: &HeaderMap) -> Result<String, StatusCode> {
     let address = headers
         .get("x-eth-address")
         .and_then(|v| v.to_str().ok())
@@ -58,21 +89,20 @@ pub async fn eth_auth_layer(
         .map(str::to_owned)
         .ok_or(StatusCode::UNAUTHORIZED)?;
 
-    // Get the current nonce from the database
-    let wallet_address = crate::domain::value_objects::WalletAddress(address.clone());
-    let nonce = state
-        .profile_repository
-        .get_login_nonce_by_wallet_address(&wallet_address)
-        .await
-        .map_err(|_| StatusCode::INTERNAL_SERVER_ERROR)?
-        .unwrap_or(1); // Use default nonce if profile doesn't exist
+    // The EIP-4361 message spans several lines, so it travels base64-encoded
+    let message = headers
+        .get("x-siwe-message")
+        .and_then(|v| v.to_str().ok())
+        .and_then(|v| Base64::decode_vec(v).ok())
+        .and_then(|v| String::from_utf8(v).ok())
+        .ok_or(StatusCode::UNAUTHORIZED)?;
 
     let result = state
         .auth_service
         .verify_signature(
             &AuthChallenge {
-                address: address.clone().to_string(),
-                nonce,
+                address: address.clone(),
+                message,
             },
             &signature,
         )
@@ -83,11 +113,7 @@ pub async fn eth_auth_layer(
         return Err(StatusCode::UNAUTHORIZED);
     }
 
-    // Inject identity for handlers:
-    req.extensions_mut()
-        .insert(VerifiedWallet(address.to_string()));
-
-    Ok(next.run(req).await)
+    Ok(address)
 }
 
 pub async fn test_auth_layer(mut req: Request<Body>, next: Next) -> Result<Response, StatusCode> {
@@ -100,3 +126,30 @@ pub async fn test_auth_layer(mut req: Request<Body>, next: Next) -> Result<Respo
     req.extensions_mut().insert(VerifiedWallet(address));
     Ok(next.run(req).await)
 }
+
+/// Lets through the requests bearing the configured admin token.
+pub async fn admin_auth_layer(
+    State(state): State<AppState>,
+    req: Request<Body>,
+    next: Next,
+) -> Result<Response, StatusCode> {
+    let admin_token = state
+        .admin_token
+        .as_deref()
+        .ok_or(StatusCode::UNAUTHORIZED)?;
+    let token = req
+        .headers()
+        .get("authorization")
+        .and_then(|v| v.to_str().ok())
+        .and_then(|v| v.strip_prefix("Bearer "))
+        .ok_or(StatusCode::UNAUTHORIZED)?;
+    // Hashes have the same length whatever the token's, and are compared in constant time
+    if !bool::from(
+        keccak256(token)
+            .as_slice()
+            .ct_eq(keccak256(admin_token).as_slice()),
+    ) {
+        return Err(StatusCode::UNAUTHORIZED);
+    }
+    Ok(next.run(req).await)
+}
@@ -0,0 +1,174 @@
+use ethers::abi::{self, Token};
+use ethers::providers::{JsonRpcError, MockProvider, MockResponse, Provider};
+use ethers::types::transaction::eip2718::TypedTransaction;
+use ethers::types::{Address, Bytes, TransactionRequest};
+use ethers::utils::hex;
+use guild_backend::domain::services::contract_wallet_verifier::ContractWalletVerifier;
+use guild_backend::domain::value_objects::WalletAddress;
+use guild_backend::infrastructure::services::rpc_contract_wallet_verifier::RpcContractWalletVerifier;
+
+const WALLET: &str = "0x1111111111111111111111111111111111111111";
+const FACTORY: &str = "0x2222222222222222222222222222222222222222";
+const HASH: [u8; 32] = [0x11; 32];
+const SIGNATURE: [u8; 3] = [0x01, 0x02, 0x03];
+const FACTORY_CALLDATA: [u8; 4] = [0xde, 0xad, 0xbe, 0xef];
+
+/// `isValidSignature(HASH, SIGNATURE)`
+fn validation_calldata() -> Bytes {
+    let calldata = [
+        "1626ba7e",
+        &"11".repeat(32),
+        "0000000000000000000000000000000000000000000000000000000000000040",
+        "0000000000000000000000000000000000000000000000000000000000000003",
+        "0102030000000000000000000000000000000000000000000000000000000000",
+    ]
+    .concat();
+    hex::decode(calldata).unwrap().into()
+}
+
+fn wrapped_signature() -> Vec<u8> {
+    let wrapped = abi::encode(&[
+        Token::Address(FACTORY.parse().unwrap()),
+        Token::Bytes(FACTORY_CALLDATA.to_vec()),
+        Token::Bytes(SIGNATURE.to_vec()),
+    ]);
+    [wrapped, [0x64, 0x92].repeat(16)].concat()
+}
+
+fn eth_call(tx: TransactionRequest) -> (TypedTransaction, &'static str) {
+    (tx.into(), "latest")
+}
+
+fn word(value: &str) -> String {
+    format!("{:0<64}", value)
+}
+
+fn returned(data: &str) -> Bytes {
+    hex::decode(data).unwrap().into()
+}
+
+fn wallet() -> WalletAddress {
+    WalletAddress::new(WALLET.to_string()).unwrap()
+}
+
+fn verifier() -> (RpcContractWalletVerifier<MockProvider>, MockProvider) {
+    let (provider, mock) = Provider::mocked();
+    (RpcContractWalletVerifier::with_provider(provider), mock)
+}
+
+fn reverted() -> MockResponse {
+    MockResponse::Error(JsonRpcError {
+        code: 3,
+        message: "execution reverted".to_string(),
+        data: None,
+    })
+}
+
+#[tokio::test]
+async fn asks_deployed_wallets_through_is_valid_signature() {
+    let (verifier, mock) = verifier();
+    let wallet_address: Address = WALLET.parse().unwrap();
+    let call = TransactionRequest::new()
+        .to(wallet_address)
+        .data(validation_calldata());
+
+    mock.push::<Bytes, _>(returned(&word("1626ba7e"))).unwrap();
+    assert!(verifier
+        .is_valid_signature(&wallet(), HASH, &SIGNATURE)
+        .await
+        .unwrap());
+    mock.assert_request("eth_call", eth_call(call.clone()))
+        .unwrap();
+
+    mock.push::<Bytes, _>(returned(&word("ffffffff"))).unwrap();
+    assert!(!verifier
+        .is_valid_signature(&wallet(), HASH, &SIGNATURE)
+        .await
+        .unwrap());
+    mock.assert_request("eth_call", eth_call(call)).unwrap();
+
+    // Reverts, e.g. when the address is not a wallet
+    mock.push_response(reverted());
+    assert!(!verifier
+        .is_valid_signature(&wallet(), HASH, &SIGNATURE)
+        .await
+        .unwrap());
+}
+
+#[tokio::test]
+async fn deploys_counterfactual_wallets_within_a_deployless_call() {
+    let (verifier, mock) = verifier();
+    let program = [
+        // Copy the factory calldata after the program to memory, then call the factory
+        "6100046100626000396000600061000460006000".to_string(),
+        format!("73{}5af150", &FACTORY[2..]),
+        // Copy the validation calldata after it, then call the wallet, keeping the 32 bytes it
+        // returns beyond both calldatas
+        "61008461006660003960206100a061008460006000".to_string(),
+        format!("73{}5af1", &WALLET[2..]),
+        // Store whether that call succeeded next to them, then return both words
+        "6100c05260406100a0f3".to_string(),
+    ]
+    .concat();
+    let deployless = [
+        hex::decode(program).unwrap(),
+        FACTORY_CALLDATA.to_vec(),
+        validation_calldata().to_vec(),
+    ]
+    .concat();
+
+    // Responses are popped from the last one pushed
+    mock.push::<Bytes, _>(returned(&format!("{}{:0>64}", word("1626ba7e"), "1")))
+        .unwrap();
+    mock.push::<Bytes, _>(Bytes::new()).unwrap();
+    assert!(verifier
+        .is_valid_signature(&wallet(), HASH, &wrapped_signature())
+        .await
+        .unwrap());
+    mock.assert_request(
+        "eth_getCode",
+        (WALLET.parse::<Address>().unwrap(), "latest"),
+    )
+    .unwrap();
+    mock.assert_request(
+        "eth_call",
+        eth_call(TransactionRequest::new().data(deployless)),
+    )
+    .unwrap();
+
+    // Whatever was left where the answer goes, the wallet reverted
+    mock.push::<Bytes, _>(returned(&format!("{}{:0>64}", word("1626ba7e"), "0")))
+        .unwrap();
+    mock.push::<Bytes, _>(Bytes::new()).unwrap();
+    assert!(!verifier
+        .is_valid_signature(&wallet(), HASH, &wrapped_signature())
+        .await
+        .unwrap());
+}
+
+#[tokio::test]
+async fn asks_counterfactual_wallets_once_deployed_about_the_inner_signature() {
+    let (verifier, mock) = verifier();
+
+    mock.push::<Bytes, _>(returned(&word("1626ba7e"))).unwrap();
+    mock.push::<Bytes, _>(Bytes::from(vec![0x60, 0x80]))
+        .unwrap();
+    assert!(verifier
+        .is_valid_signature(&wallet(), HASH, &wrapped_signature())
+        .await
+        .unwrap());
+    mock.assert_request(
+        "eth_getCode",
+        (WALLET.parse::<Address>().unwrap(), "latest"),
+    )
+    .unwrap();
+    mock.assert_request(
+        "eth_call",
+        eth_call(
+            TransactionRequest::new()
+                .to(WALLET.parse::<Address>().unwrap())
+                .data(validation_calldata()),
+        ),
+    )
+    .unwrap();
+}
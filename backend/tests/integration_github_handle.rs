@@ -1,8 +1,18 @@
 use guild_backend::application::dtos::profile_dtos::ProfileResponse;
+use guild_backend::infrastructure::jwt::{JwtConfig, JwtKeys};
 use guild_backend::presentation::api::{test_api, AppState};
 use serde_json::json;
 use tokio::net::TcpListener;
 
+fn jwt_config() -> JwtConfig {
+    JwtConfig {
+        keys: JwtKeys::Secret("test-secret".to_string()),
+        issuer: "guild-backend".to_string(),
+        audience: "guild".to_string(),
+        expiration: 900,
+    }
+}
+
 #[tokio::test]
 async fn valid_github_handle_works() {
     std::env::set_var("TEST_MODE", "1");
@@ -16,10 +26,27 @@ async fn valid_github_handle_works() {
     let profile_repository = std::sync::Arc::new(
         guild_backend::infrastructure::repositories::PostgresProfileRepository::new(pool.clone()),
     );
-    let auth_service = guild_backend::infrastructure::services::ethereum_address_verification_service::EthereumAddressVerificationService::new(profile_repository.clone());
+    let nonce_repository = std::sync::Arc::new(
+        guild_backend::infrastructure::repositories::PostgresNonceRepository::new(pool.clone()),
+    );
+    let session_repository = std::sync::Arc::new(
+        guild_backend::infrastructure::repositories::PostgresSessionRepository::new(pool.clone()),
+    );
+    let auth_service = guild_backend::infrastructure::services::ethereum_address_verification_service::MockEthereumAddressVerificationService::new();
     let state = AppState {
         profile_repository,
+        nonce_repository,
+        session_repository,
+        jwt_manager: std::sync::Arc::new(
+            guild_backend::infrastructure::jwt::JwtManager::with_config(jwt_config()).unwrap(),
+        ),
+        refresh_token_manager: std::sync::Arc::new(
+            guild_backend::infrastructure::refresh_token::RefreshTokenManager::with_expiration(
+                3600,
+            ),
+        ),
         auth_service: std::sync::Arc::new(auth_service),
+        admin_token: None,
     };
     let app = test_api(state);
 
@@ -84,10 +111,27 @@ async fn invalid_format_rejected() {
     let profile_repository = std::sync::Arc::new(
         guild_backend::infrastructure::repositories::PostgresProfileRepository::new(pool.clone()),
     );
-    let auth_service = guild_backend::infrastructure::services::ethereum_address_verification_service::EthereumAddressVerificationService::new(profile_repository.clone());
+    let nonce_repository = std::sync::Arc::new(
+        guild_backend::infrastructure::repositories::PostgresNonceRepository::new(pool.clone()),
+    );
+    let session_repository = std::sync::Arc::new(
+        guild_backend::infrastructure::repositories::PostgresSessionRepository::new(pool.clone()),
+    );
+    let auth_service = guild_backend::infrastructure::services::ethereum_address_verification_service::MockEthereumAddressVerificationService::new();
     let state = AppState {
         profile_repository,
+        nonce_repository,
+        session_repository,
+        jwt_manager: std::sync::Arc::new(
+            guild_backend::infrastructure::jwt::JwtManager::with_config(jwt_config()).unwrap(),
+        ),
+        refresh_token_manager: std::sync::Arc::new(
+            guild_backend::infrastructure::refresh_token::RefreshTokenManager::with_expiration(
+                3600,
+            ),
+        ),
         auth_service: std::sync::Arc::new(auth_service),
+        admin_token: None,
     };
     let app = test_api(state);
 
@@ -159,10 +203,27 @@ async fn conflict_case_insensitive() {
     let profile_repository = std::sync::Arc::new(
         guild_backend::infrastructure::repositories::PostgresProfileRepository::new(pool.clone()),
     );
-    let auth_service = guild_backend::infrastructure::services::ethereum_address_verification_service::EthereumAddressVerificationService::new(profile_repository.clone());
+    let nonce_repository = std::sync::Arc::new(
+        guild_backend::infrastructure::repositories::PostgresNonceRepository::new(pool.clone()),
+    );
+    let session_repository = std::sync::Arc::new(
+        guild_backend::infrastructure::repositories::PostgresSessionRepository::new(pool.clone()),
+    );
+    let auth_service = guild_backend::infrastructure::services::ethereum_address_verification_service::MockEthereumAddressVerificationService::new();
     let state = AppState {
         profile_repository,
+        nonce_repository,
+        session_repository,
+        jwt_manager: std::sync::Arc::new(
+            guild_backend::infrastructure::jwt::JwtManager::with_config(jwt_config()).unwrap(),
+        ),
+        refresh_token_manager: std::sync::Arc::new(
+            guild_backend::infrastructure::refresh_token::RefreshTokenManager::with_expiration(
+                3600,
+            ),
+        ),
         auth_service: std::sync::Arc::new(auth_service),
+        admin_token: None,
     };
     let app = test_api(state);
 
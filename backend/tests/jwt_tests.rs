@@ -0,0 +1,127 @@
+use std::path::PathBuf;
+
+use guild_backend::infrastructure::jwt::{JwtConfig, JwtKeys, JwtManager};
+use jsonwebtoken::jwk::AlgorithmParameters;
+use jsonwebtoken::{decode_header, Algorithm};
+use uuid::Uuid;
+
+const ADDRESS: &str = "0x742d35Cc6634C0532925a3b844Bc454e4438f44e";
+
+fn fixture(path: &str) -> PathBuf {
+    PathBuf::from(env!("CARGO_MANIFEST_DIR"))
+        .join("tests/fixtures/jwt")
+        .join(path)
+}
+
+fn key_files_config(algorithm: Algorithm, dir: &str, key_id: &str) -> JwtConfig {
+    JwtConfig {
+        keys: JwtKeys::KeyFiles {
+            algorithm,
+            private_key_file: fixture(&format!("{}/{}.private.pem", dir, key_id)),
+            key_id: key_id.to_string(),
+            public_keys_dir: fixture(&format!("{}/keys", dir)),
+        },
+        issuer: "guild-backend".to_string(),
+        audience: "guild".to_string(),
+        expiration: 900,
+    }
+}
+
+#[test]
+fn signs_with_rs256_and_keeps_verifying_tokens_of_the_previous_key() {
+    let before =
+        JwtManager::with_config(key_files_config(Algorithm::RS256, "rs256", "key-a")).unwrap();
+    let session_id = Uuid::new_v4();
+    let token = before.generate_token(ADDRESS, session_id).unwrap();
+
+    let header = decode_header(&token).unwrap();
+    assert_eq!(header.alg, Algorithm::RS256);
+    assert_eq!(header.kid.as_deref(), Some("key-a"));
+
+    let claims = before.validate_token(&token).unwrap();
+    assert_eq!(claims.sub, ADDRESS);
+    assert_eq!(claims.sid, session_id);
+    assert_eq!(claims.iss, "guild-backend");
+    assert_eq!(claims.aud, "guild");
+    assert_eq!(claims.exp, claims.iat + 900);
+
+    // After rotating to key-b, tokens signed with key-a stay valid while its public key is kept
+    let after =
+        JwtManager::with_config(key_files_config(Algorithm::RS256, "rs256", "key-b")).unwrap();
+    assert_eq!(after.validate_token(&token).unwrap().sid, session_id);
+    let rotated = after.generate_token(ADDRESS, session_id).unwrap();
+    assert_eq!(
+        decode_header(&rotated).unwrap().kid.as_deref(),
+        Some("key-b")
+    );
+    assert!(before.validate_token(&rotated).is_ok());
+
+    let kids: Vec<_> = after
+        .jwks()
+        .keys
+        .iter()
+        .map(|jwk| jwk.common.key_id.clone().unwrap())
+        .collect();
+    assert_eq!(kids, ["key-a", "key-b"]);
+    assert!(after
+        .jwks()
+        .keys
+        .iter()
+        .all(|jwk| matches!(jwk.algorithm, AlgorithmParameters::RSA(_))));
+}
+
+#[test]
+fn signs_with_eddsa_and_publishes_an_okp_key() {
+    let manager =
+        JwtManager::with_config(key_files_config(Algorithm::EdDSA, "eddsa", "ed-1")).unwrap();
+    let token = manager.generate_token(ADDRESS, Uuid::new_v4()).unwrap();
+
+    assert_eq!(decode_header(&token).unwrap().alg, Algorithm::EdDSA);
+    assert_eq!(manager.validate_token(&token).unwrap().sub, ADDRESS);
+
+    let [jwk] = manager.jwks().keys.as_slice() else {
+        panic!("expected a single key");
+    };
+    assert_eq!(jwk.common.key_id.as_deref(), Some("ed-1"));
+    assert!(matches!(
+        jwk.algorithm,
+        AlgorithmParameters::OctetKeyPair(_)
+    ));
+}
+
+#[test]
+fn rejects_tokens_for_another_audience_issuer_or_key() {
+    let manager =
+        JwtManager::with_config(key_files_config(Algorithm::RS256, "rs256", "key-a")).unwrap();
+
+    let mut config = key_files_config(Algorithm::RS256, "rs256", "key-a");
+    config.audience = "indexer".to_string();
+    let other_audience = JwtManager::with_config(config)
+        .unwrap()
+        .generate_token(ADDRESS, Uuid::new_v4())
+        .unwrap();
+    assert!(manager.validate_token(&other_audience).is_err());
+
+    let mut config = key_files_config(Algorithm::RS256, "rs256", "key-a");
+    config.issuer = "someone-else".to_string();
+    let other_issuer = JwtManager::with_config(config)
+        .unwrap()
+        .generate_token(ADDRESS, Uuid::new_v4())
+        .unwrap();
+    assert!(manager.validate_token(&other_issuer).is_err());
+
+    // Signed with a shared secret, so without any key id
+    let secret = JwtManager::with_config(JwtConfig {
+        keys: JwtKeys::Secret("test-secret".to_string()),
+        issuer: "guild-backend".to_string(),
+        audience: "guild".to_string(),
+        expiration: 900,
+    })
+    .unwrap();
+    let without_kid = secret.generate_token(ADDRESS, Uuid::new_v4()).unwrap();
+    assert!(manager.validate_token(&without_kid).is_err());
+    assert!(secret.jwks().keys.is_empty());
+
+    // The signing key must be published for verification
+    assert!(JwtManager::with_config(key_files_config(Algorithm::RS256, "rs256", "key-c")).is_err());
+}
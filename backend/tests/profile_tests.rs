@@ -60,20 +60,6 @@ mod github_handle_tests {
                 })
                 .cloned())
         }
-
-        async fn get_login_nonce_by_wallet_address(
-            &self,
-            _address: &WalletAddress,
-        ) -> Result<Option<i64>, Box<dyn std::error::Error>> {
-            Ok(Some(1))
-        }
-
-        async fn increment_login_nonce(
-            &self,
-            _address: &WalletAddress,
-        ) -> Result<(), Box<dyn std::error::Error>> {
-            Ok(())
-        }
     }
 
     #[tokio::test]
@@ -86,7 +72,6 @@ mod github_handle_tests {
             description: None,
             avatar_url: None,
             github_login: None,
-            login_nonce: 1,
             created_at: chrono::Utc::now(),
             updated_at: chrono::Utc::now(),
         };
@@ -117,7 +102,6 @@ mod github_handle_tests {
             description: None,
             avatar_url: None,
             github_login: None,
-            login_nonce: 1,
             created_at: chrono::Utc::now(),
             updated_at: chrono::Utc::now(),
         };
@@ -149,7 +133,6 @@ mod github_handle_tests {
             description: None,
             avatar_url: None,
             github_login: Some("Alice".into()),
-            login_nonce: 1,
             created_at: chrono::Utc::now(),
             updated_at: chrono::Utc::now(),
         };
@@ -160,7 +143,6 @@ mod github_handle_tests {
             description: None,
             avatar_url: None,
             github_login: None,
-            login_nonce: 1,
             created_at: chrono::Utc::now(),
             updated_at: chrono::Utc::now(),
         };
@@ -192,7 +174,6 @@ mod github_handle_tests {
             description: None,
             avatar_url: None,
             github_login: Some("BobUser".into()),
-            login_nonce: 1,
             created_at: chrono::Utc::now(),
             updated_at: chrono::Utc::now(),
         };
@@ -223,7 +204,6 @@ mod github_handle_tests {
             description: None,
             avatar_url: None,
             github_login: Some("CharlieGit".into()),
-            login_nonce: 1,
             created_at: chrono::Utc::now(),
             updated_at: chrono::Utc::now(),
         };
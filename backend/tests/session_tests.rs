@@ -0,0 +1,330 @@
+use std::sync::{Arc, Mutex};
+
+use axum::body::Body;
+use axum::http::{Request, StatusCode};
+use base64ct::{Base64, Encoding};
+use chrono::{DateTime, Utc};
+use guild_backend::application::commands::{
+    login::login, logout::logout, refresh_session::refresh_session,
+    revoke_sessions::revoke_sessions,
+};
+use guild_backend::domain::entities::session::Session;
+use guild_backend::domain::repositories::SessionRepository;
+use guild_backend::domain::value_objects::WalletAddress;
+use guild_backend::infrastructure::jwt::{JwtConfig, JwtKeys, JwtManager};
+use guild_backend::infrastructure::refresh_token::RefreshTokenManager;
+use guild_backend::infrastructure::repositories::{
+    PostgresNonceRepository, PostgresProfileRepository,
+};
+use guild_backend::infrastructure::services::ethereum_address_verification_service::MockEthereumAddressVerificationService;
+use guild_backend::presentation::api::{api, AppState};
+use tower::ServiceExt;
+use uuid::Uuid;
+
+const ADDRESS: &str = "0x742d35Cc6634C0532925a3b844Bc454e4438f44e";
+
+// A fake in-memory session store
+#[derive(Default)]
+struct FakeSessions {
+    sessions: Mutex<Vec<Session>>,
+}
+
+impl FakeSessions {
+    fn is_active(&self, id: Uuid) -> bool {
+        let sessions = self.sessions.lock().unwrap();
+        sessions.iter().any(|s| s.id == id && s.is_active())
+    }
+}
+
+#[async_trait::async_trait]
+impl SessionRepository for FakeSessions {
+    async fn create(&self, session: &Session) -> Result<(), Box<dyn std::error::Error>> {
+        self.sessions.lock().unwrap().push(session.clone());
+        Ok(())
+    }
+
+    async fn find_by_id(&self, id: Uuid) -> Result<Option<Session>, Box<dyn std::error::Error>> {
+        let sessions = self.sessions.lock().unwrap();
+        Ok(sessions.iter().find(|s| s.id == id).cloned())
+    }
+
+    async fn find_by_refresh_token_hash(
+        &self,
+        hash: &str,
+    ) -> Result<Option<Session>, Box<dyn std::error::Error>> {
+        let sessions = self.sessions.lock().unwrap();
+        Ok(sessions
+            .iter()
+            .find(|s| {
+                s.refresh_token_hash == hash
+                    || s.previous_refresh_token_hash.as_deref() == Some(hash)
+            })
+            .cloned())
+    }
+
+    async fn rotate_refresh_token(
+        &self,
+        id: Uuid,
+        current_hash: &str,
+        new_hash: &str,
+        expires_at: DateTime<Utc>,
+    ) -> Result<bool, Box<dyn std::error::Error>> {
+        let mut sessions = self.sessions.lock().unwrap();
+        let Some(session) = sessions
+            .iter_mut()
+            .find(|s| s.id == id && s.refresh_token_hash == current_hash && s.is_active())
+        else {
+            return Ok(false);
+        };
+        session.previous_refresh_token_hash = Some(current_hash.to_string());
+        session.refresh_token_hash = new_hash.to_string();
+        session.expires_at = expires_at;
+        Ok(true)
+    }
+
+    async fn revoke(&self, id: Uuid) -> Result<(), Box<dyn std::error::Error>> {
+        let mut sessions = self.sessions.lock().unwrap();
+        for session in sessions.iter_mut().filter(|s| s.id == id) {
+            session.revoked_at.get_or_insert_with(Utc::now);
+        }
+        Ok(())
+    }
+
+    async fn revoke_all_by_address(
+        &self,
+        address: &WalletAddress,
+    ) -> Result<u64, Box<dyn std::error::Error>> {
+        let mut sessions = self.sessions.lock().unwrap();
+        let mut revoked = 0;
+        for session in sessions
+            .iter_mut()
+            .filter(|s| s.address.as_str().eq_ignore_ascii_case(address.as_str()) && s.is_active())
+        {
+            session.revoked_at = Some(Utc::now());
+            revoked += 1;
+        }
+        Ok(revoked)
+    }
+}
+
+fn jwt_manager() -> Arc<JwtManager> {
+    Arc::new(
+        JwtManager::with_config(JwtConfig {
+            keys: JwtKeys::Secret("test-secret".to_string()),
+            issuer: "guild-backend".to_string(),
+            audience: "guild".to_string(),
+            expiration: 900,
+        })
+        .unwrap(),
+    )
+}
+
+fn refresh_token_manager() -> Arc<RefreshTokenManager> {
+    Arc::new(RefreshTokenManager::with_expiration(3600))
+}
+
+fn session_id(token: &str) -> Uuid {
+    jwt_manager().validate_token(token).unwrap().sid
+}
+
+#[tokio::test]
+async fn rotates_refresh_tokens_and_revokes_the_session_when_one_is_reused() {
+    let jwt = jwt_manager();
+    let refresh_tokens = refresh_token_manager();
+    let sessions = Arc::new(FakeSessions::default());
+
+    let first = login(
+        sessions.clone(),
+        jwt.clone(),
+        refresh_tokens.clone(),
+        ADDRESS.to_string(),
+    )
+    .await
+    .unwrap();
+    let id = session_id(&first.token);
+    assert!(sessions.is_active(id));
+
+    let second = refresh_session(
+        sessions.clone(),
+        jwt.clone(),
+        refresh_tokens.clone(),
+        first.refresh_token.clone(),
+    )
+    .await
+    .unwrap();
+    assert_ne!(second.refresh_token, first.refresh_token);
+    assert_eq!(session_id(&second.token), id);
+    assert_eq!(second.address, ADDRESS);
+
+    // The first refresh token was exchanged already, so whoever presents it again stole one
+    // of the two
+    assert!(refresh_session(
+        sessions.clone(),
+        jwt.clone(),
+        refresh_tokens.clone(),
+        first.refresh_token
+    )
+    .await
+    .is_err());
+    assert!(!sessions.is_active(id));
+    assert!(refresh_session(
+        sessions.clone(),
+        jwt.clone(),
+        refresh_tokens.clone(),
+        second.refresh_token
+    )
+    .await
+    .is_err());
+
+    assert!(
+        refresh_session(sessions, jwt, refresh_tokens, "unknown".to_string())
+            .await
+            .is_err()
+    );
+}
+
+#[tokio::test]
+async fn logs_out_one_session_and_revokes_all_sessions_of_an_address() {
+    let jwt = jwt_manager();
+    let refresh_tokens = refresh_token_manager();
+    let sessions = Arc::new(FakeSessions::default());
+
+    let laptop = login(
+        sessions.clone(),
+        jwt.clone(),
+        refresh_tokens.clone(),
+        ADDRESS.to_string(),
+    )
+    .await
+    .unwrap();
+    let phone = login(
+        sessions.clone(),
+        jwt.clone(),
+        refresh_tokens.clone(),
+        ADDRESS.to_string(),
+    )
+    .await
+    .unwrap();
+    let other = login(
+        sessions.clone(),
+        jwt.clone(),
+        refresh_tokens.clone(),
+        "0x2581aAa94299787a8A588B2Fceb161A302939E28".to_string(),
+    )
+    .await
+    .unwrap();
+
+    logout(
+        sessions.clone(),
+        refresh_tokens.clone(),
+        laptop.refresh_token.clone(),
+    )
+    .await
+    .unwrap();
+    assert!(!sessions.is_active(session_id(&laptop.token)));
+    assert!(sessions.is_active(session_id(&phone.token)));
+    assert!(refresh_session(
+        sessions.clone(),
+        jwt.clone(),
+        refresh_tokens.clone(),
+        laptop.refresh_token.clone()
+    )
+    .await
+    .is_err());
+    // Logging out twice is harmless
+    logout(
+        sessions.clone(),
+        refresh_tokens.clone(),
+        laptop.refresh_token,
+    )
+    .await
+    .unwrap();
+
+    let revoked = revoke_sessions(sessions.clone(), ADDRESS.to_lowercase())
+        .await
+        .unwrap();
+    assert_eq!(revoked.revoked, 1);
+    assert!(!sessions.is_active(session_id(&phone.token)));
+    assert!(sessions.is_active(session_id(&other.token)));
+}
+
+fn app(sessions: Arc<FakeSessions>, admin_token: Option<&str>) -> axum::Router {
+    // Never connects, since no request below reads profiles or nonces
+    let pool = sqlx::PgPool::connect_lazy("postgres://localhost/unused").unwrap();
+    api(AppState {
+        profile_repository: Arc::new(PostgresProfileRepository::new(pool.clone())),
+        nonce_repository: Arc::new(PostgresNonceRepository::new(pool)),
+        session_repository: sessions,
+        jwt_manager: jwt_manager(),
+        refresh_token_manager: refresh_token_manager(),
+        auth_service: Arc::new(MockEthereumAddressVerificationService::new()),
+        admin_token: admin_token.map(str::to_string),
+    })
+}
+
+#[tokio::test]
+async fn only_a_signed_message_opens_a_session() {
+    let jwt = jwt_manager();
+    let refresh_tokens = refresh_token_manager();
+    let sessions = Arc::new(FakeSessions::default());
+    let app = app(sessions.clone(), None);
+
+    let session = login(sessions.clone(), jwt, refresh_tokens, ADDRESS.to_string())
+        .await
+        .unwrap();
+    let with_access_token = app
+        .clone()
+        .oneshot(
+            Request::post("/auth/login")
+                .header("authorization", format!("Bearer {}", session.token))
+                .body(Body::empty())
+                .unwrap(),
+        )
+        .await
+        .unwrap();
+    assert_eq!(with_access_token.status(), StatusCode::UNAUTHORIZED);
+
+    let with_signature = app
+        .oneshot(
+            Request::post("/auth/login")
+                .header("x-eth-address", ADDRESS)
+                .header("x-eth-signature", "0x00")
+                .header("x-siwe-message", Base64::encode_string(b"signed message"))
+                .body(Body::empty())
+                .unwrap(),
+        )
+        .await
+        .unwrap();
+    assert_eq!(with_signature.status(), StatusCode::OK);
+    assert_eq!(sessions.sessions.lock().unwrap().len(), 2);
+}
+
+#[tokio::test]
+async fn only_the_admin_token_revokes_sessions() {
+    let sessions = Arc::new(FakeSessions::default());
+    let app = app(sessions.clone(), Some("admin-secret"));
+    login(
+        sessions.clone(),
+        jwt_manager(),
+        refresh_token_manager(),
+        ADDRESS.to_string(),
+    )
+    .await
+    .unwrap();
+
+    let revoke = |token: &str| {
+        Request::delete(format!("/admin/sessions/{}", ADDRESS))
+            .header("authorization", format!("Bearer {}", token))
+            .body(Body::empty())
+            .unwrap()
+    };
+    for token in ["admin-secre", "admin-secreT", "admin-secret-"] {
+        let response = app.clone().oneshot(revoke(token)).await.unwrap();
+        assert_eq!(response.status(), StatusCode::UNAUTHORIZED);
+    }
+    assert!(sessions.sessions.lock().unwrap()[0].is_active());
+
+    let response = app.oneshot(revoke("admin-secret")).await.unwrap();
+    assert_eq!(response.status(), StatusCode::OK);
+    assert!(!sessions.sessions.lock().unwrap()[0].is_active());
+}
@@ -0,0 +1,384 @@
+use std::collections::HashMap;
+use std::sync::{Arc, Mutex};
+
+use chrono::{DateTime, Duration, SecondsFormat, Utc};
+use ethers::signers::{LocalWallet, Signer};
+use ethers::utils::to_checksum;
+use guild_backend::application::commands::issue_login_nonce::issue_login_nonce;
+use guild_backend::domain::repositories::NonceRepository;
+use guild_backend::domain::services::auth_service::{AuthChallenge, AuthService};
+use guild_backend::domain::services::contract_wallet_verifier::ContractWalletVerifier;
+use guild_backend::domain::value_objects::{Nonce, WalletAddress};
+use guild_backend::infrastructure::services::ethereum_address_verification_service::{
+    EthereumAddressVerificationService, SiweConfig,
+};
+
+const PRIVATE_KEY: &str = "0x4c0883a69102937d6231471b5dbb6204fe5129617082792ae468d01a3f362318";
+const NONCE: &str = "4f9c2b7e1a3d4e6f8a0b1c2d3e4f5a6b";
+
+// Nonces issued so far, with the address they were issued to and when they expire
+#[derive(Default)]
+struct FakeNonces {
+    issued: Mutex<HashMap<String, (String, DateTime<Utc>)>>,
+}
+
+impl FakeNonces {
+    fn issue(&self, address: &str, nonce: &str, expires_in: Duration) {
+        self.issued.lock().unwrap().insert(
+            nonce.to_string(),
+            (address.to_string(), Utc::now() + expires_in),
+        );
+    }
+
+    fn is_issued(&self, nonce: &str) -> bool {
+        self.issued.lock().unwrap().contains_key(nonce)
+    }
+}
+
+#[async_trait::async_trait]
+impl NonceRepository for FakeNonces {
+    async fn save(
+        &self,
+        address: &WalletAddress,
+        nonce: &Nonce,
+        expires_at: DateTime<Utc>,
+        max_outstanding: i64,
+    ) -> Result<bool, Box<dyn std::error::Error>> {
+        let mut issued = self.issued.lock().unwrap();
+        let outstanding = issued
+            .values()
+            .filter(|(to, expires_at)| {
+                to.eq_ignore_ascii_case(address.as_str()) && *expires_at > Utc::now()
+            })
+            .count();
+        if outstanding as i64 >= max_outstanding {
+            return Ok(false);
+        }
+        issued.insert(
+            nonce.value().to_string(),
+            (address.as_str().to_string(), expires_at),
+        );
+        Ok(true)
+    }
+
+    async fn consume(
+        &self,
+        address: &WalletAddress,
+        nonce: &Nonce,
+    ) -> Result<bool, Box<dyn std::error::Error>> {
+        let mut issued = self.issued.lock().unwrap();
+        let valid = issued.get(nonce.value()).is_some_and(|(to, expires_at)| {
+            to.eq_ignore_ascii_case(address.as_str()) && *expires_at > Utc::now()
+        });
+        if valid {
+            issued.remove(nonce.value());
+        }
+        Ok(valid)
+    }
+
+    async fn delete_expired(&self) -> Result<(), Box<dyn std::error::Error>> {
+        self.issued
+            .lock()
+            .unwrap()
+            .retain(|_, (_, expires_at)| *expires_at > Utc::now());
+        Ok(())
+    }
+}
+
+// Stands in for a chain where a single smart contract wallet accepts a single signature
+struct FakeContractWallet {
+    address: String,
+    signature: Vec<u8>,
+    hashes: Mutex<Vec<[u8; 32]>>,
+}
+
+#[async_trait::async_trait]
+impl ContractWalletVerifier for FakeContractWallet {
+    async fn is_valid_signature(
+        &self,
+        address: &WalletAddress,
+        hash: [u8; 32],
+        signature: &[u8],
+    ) -> Result<bool, Box<dyn std::error::Error>> {
+        self.hashes.lock().unwrap().push(hash);
+        Ok(address.as_str() == self.address && signature == self.signature)
+    }
+}
+
+fn service(nonces: Arc<FakeNonces>) -> EthereumAddressVerificationService {
+    service_with_contract_wallets(nonces, None)
+}
+
+fn service_with_contract_wallets(
+    nonces: Arc<FakeNonces>,
+    contract_wallet_verifier: Option<Arc<dyn ContractWalletVerifier>>,
+) -> EthereumAddressVerificationService {
+    EthereumAddressVerificationService::new(
+        nonces,
+        contract_wallet_verifier,
+        SiweConfig {
+            domain: "theguild.dev".to_string(),
+            chain_id: Some(80002),
+            max_age: time::Duration::minutes(10),
+        },
+    )
+}
+
+fn siwe_message(domain: &str, address: &str, issued_at: Duration, expires_in: Duration) -> String {
+    let now = Utc::now();
+    format!(
+        "{domain} wants you to sign in with your Ethereum account:\n\
+         {address}\n\
+         \n\
+         Sign in to The Guild.\n\
+         \n\
+         URI: https://{domain}\n\
+         Version: 1\n\
+         Chain ID: 80002\n\
+         Nonce: {NONCE}\n\
+         Issued At: {}\n\
+         Expiration Time: {}",
+        (now + issued_at).to_rfc3339_opts(SecondsFormat::Millis, true),
+        (now + expires_in).to_rfc3339_opts(SecondsFormat::Millis, true),
+    )
+}
+
+async fn sign_in(
+    service: &EthereumAddressVerificationService,
+    wallet: &LocalWallet,
+    message: &str,
+) -> bool {
+    let signature = wallet.sign_message(message).await.unwrap();
+    service
+        .verify_signature(
+            &AuthChallenge {
+                address: to_checksum(&wallet.address(), None),
+                message: message.to_string(),
+            },
+            &format!("0x{}", signature),
+        )
+        .await
+        .unwrap()
+        .is_some()
+}
+
+#[tokio::test]
+async fn accepts_a_fresh_message_bound_to_the_domain_once() {
+    let nonces = Arc::new(FakeNonces::default());
+    let service = service(nonces.clone());
+    let wallet: LocalWallet = PRIVATE_KEY.parse().unwrap();
+    let address = to_checksum(&wallet.address(), None);
+    nonces.issue(&address, NONCE, Duration::minutes(5));
+
+    let message = siwe_message(
+        "theguild.dev",
+        &address,
+        Duration::zero(),
+        Duration::minutes(5),
+    );
+    assert!(sign_in(&service, &wallet, &message).await);
+    assert!(!nonces.is_issued(NONCE));
+
+    // Replaying the same signed message fails once its nonce is used up
+    assert!(!sign_in(&service, &wallet, &message).await);
+}
+
+#[tokio::test]
+async fn rejects_other_domains_and_stale_or_expired_messages() {
+    let nonces = Arc::new(FakeNonces::default());
+    let service = service(nonces.clone());
+    let wallet: LocalWallet = PRIVATE_KEY.parse().unwrap();
+    let address = to_checksum(&wallet.address(), None);
+    nonces.issue(&address, NONCE, Duration::minutes(5));
+
+    let phished = siwe_message(
+        "evil.example",
+        &address,
+        Duration::zero(),
+        Duration::minutes(5),
+    );
+    assert!(!sign_in(&service, &wallet, &phished).await);
+
+    let expired = siwe_message(
+        "theguild.dev",
+        &address,
+        Duration::minutes(-2),
+        Duration::minutes(-1),
+    );
+    assert!(!sign_in(&service, &wallet, &expired).await);
+
+    let stale = siwe_message(
+        "theguild.dev",
+        &address,
+        Duration::hours(-1),
+        Duration::hours(1),
+    );
+    assert!(!sign_in(&service, &wallet, &stale).await);
+
+    // Signed by another wallet than the one the message names
+    let other: LocalWallet = "0x0123456789012345678901234567890123456789012345678901234567890123"
+        .parse()
+        .unwrap();
+    let message = siwe_message(
+        "theguild.dev",
+        &address,
+        Duration::zero(),
+        Duration::minutes(5),
+    );
+    let signature = other.sign_message(&message).await.unwrap();
+    let result = service
+        .verify_signature(
+            &AuthChallenge {
+                address: address.clone(),
+                message,
+            },
+            &format!("0x{}", signature),
+        )
+        .await
+        .unwrap();
+    assert!(result.is_none());
+
+    // Rejected messages leave the nonce for a valid one
+    assert!(nonces.is_issued(NONCE));
+}
+
+#[tokio::test]
+async fn issues_fresh_alphanumeric_nonces_to_any_wallet() {
+    let nonces = Arc::new(FakeNonces::default());
+    nonces.issue(
+        "0x2581aAa94299787a8A588B2Fceb161A302939E28",
+        NONCE,
+        Duration::seconds(-1),
+    );
+    let address = "0x742d35Cc6634C0532925a3b844Bc454e4438f44e".to_string();
+
+    let first = issue_login_nonce(nonces.clone(), address.clone())
+        .await
+        .unwrap();
+    let second = issue_login_nonce(nonces.clone(), address.clone())
+        .await
+        .unwrap();
+    assert_ne!(first.nonce, second.nonce);
+    assert!(first.nonce.len() >= 8 && first.nonce.chars().all(|c| c.is_ascii_alphanumeric()));
+    assert!(first.expires_at <= Utc::now() + Duration::minutes(5));
+    assert!(nonces.is_issued(&first.nonce) && nonces.is_issued(&second.nonce));
+
+    assert!(issue_login_nonce(nonces, "not an address".to_string())
+        .await
+        .is_err());
+}
+
+#[tokio::test]
+async fn caps_the_nonces_a_wallet_holds_until_they_are_used_or_expire() {
+    let nonces = Arc::new(FakeNonces::default());
+    let service = service(nonces.clone());
+    let wallet: LocalWallet = PRIVATE_KEY.parse().unwrap();
+    let address = to_checksum(&wallet.address(), None);
+    nonces.issue(&address, "expired", Duration::seconds(-1));
+
+    for _ in 0..4 {
+        issue_login_nonce(nonces.clone(), address.clone())
+            .await
+            .unwrap();
+    }
+    nonces.issue(&address, NONCE, Duration::minutes(5));
+    let error = issue_login_nonce(nonces.clone(), address.clone())
+        .await
+        .unwrap_err();
+    assert!(error.starts_with("Too many nonces"));
+    // Other wallets are not affected
+    assert!(issue_login_nonce(
+        nonces.clone(),
+        "0x2581aAa94299787a8A588B2Fceb161A302939E28".to_string()
+    )
+    .await
+    .is_ok());
+
+    // Signing in uses a nonce up, making room for another
+    let message = siwe_message(
+        "theguild.dev",
+        &address,
+        Duration::zero(),
+        Duration::minutes(5),
+    );
+    assert!(sign_in(&service, &wallet, &message).await);
+    assert!(!sign_in(&service, &wallet, &message).await);
+    assert!(issue_login_nonce(nonces, address).await.is_ok());
+}
+
+#[tokio::test]
+async fn rejects_nonces_not_issued_to_the_wallet_or_expired() {
+    let nonces = Arc::new(FakeNonces::default());
+    let service = service(nonces.clone());
+    let wallet: LocalWallet = PRIVATE_KEY.parse().unwrap();
+    let address = to_checksum(&wallet.address(), None);
+    let message = siwe_message(
+        "theguild.dev",
+        &address,
+        Duration::zero(),
+        Duration::minutes(5),
+    );
+
+    // Never issued
+    assert!(!sign_in(&service, &wallet, &message).await);
+
+    nonces.issue(
+        "0x2581aAa94299787a8A588B2Fceb161A302939E28",
+        NONCE,
+        Duration::minutes(5),
+    );
+    assert!(!sign_in(&service, &wallet, &message).await);
+
+    nonces.issue(&address, NONCE, Duration::seconds(-1));
+    assert!(!sign_in(&service, &wallet, &message).await);
+}
+
+#[tokio::test]
+async fn asks_smart_contract_wallets_about_signatures_they_did_not_sign_with_a_key() {
+    let nonces = Arc::new(FakeNonces::default());
+    let safe = "0x2581aAa94299787a8A588B2Fceb161A302939E28";
+    let wallet = Arc::new(FakeContractWallet {
+        address: safe.to_string(),
+        signature: vec![0xab; 130],
+        hashes: Mutex::new(vec![]),
+    });
+    let service = service_with_contract_wallets(nonces.clone(), Some(wallet.clone()));
+    nonces.issue(safe, NONCE, Duration::minutes(5));
+
+    let message = siwe_message("theguild.dev", safe, Duration::zero(), Duration::minutes(5));
+    let challenge = AuthChallenge {
+        address: safe.to_string(),
+        message: message.clone(),
+    };
+    let rejected = service
+        .verify_signature(&challenge, &format!("0x{}", "cd".repeat(130)))
+        .await
+        .unwrap();
+    assert!(rejected.is_none());
+    let accepted = service
+        .verify_signature(&challenge, &format!("0x{}", "ab".repeat(130)))
+        .await
+        .unwrap();
+    assert!(accepted.is_some());
+
+    // The wallet is asked about the EIP-191 hash of the message, as for `personal_sign`
+    let hash = ethers::utils::hash_message(&message);
+    assert_eq!(*wallet.hashes.lock().unwrap(), vec![hash.0, hash.0]);
+    assert!(!nonces.is_issued(NONCE));
+
+    // Messages failing the other checks never reach the chain
+    nonces.issue(safe, NONCE, Duration::minutes(5));
+    let phished = siwe_message("evil.example", safe, Duration::zero(), Duration::minutes(5));
+    let result = service
+        .verify_signature(
+            &AuthChallenge {
+                address: safe.to_string(),
+                message: phished,
+            },
+            &format!("0x{}", "ab".repeat(130)),
+        )
+        .await
+        .unwrap();
+    assert!(result.is_none());
+    assert_eq!(wallet.hashes.lock().unwrap().len(), 2);
+}
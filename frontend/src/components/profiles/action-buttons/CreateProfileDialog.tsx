@@ -41,11 +41,14 @@ export function CreateProfileButton() {
   const [open, setOpen] = useState(false);
   const createProfile = useCreateProfile();
   const queryClient = useQueryClient();
-  const { address } = useAccount();
+  const { address, chainId } = useAccount();
   const { signMessageAsync } = useSignMessage();
   const { data: nonceData, isLoading: isLoadingNonce } = useGetNonce(address);
 
-  const siweMessage = nonceData ? generateSiweMessage(nonceData) : "";
+  const siweMessage =
+    address && chainId && nonceData
+      ? generateSiweMessage({ address, chainId, nonce: nonceData })
+      : "";
 
   const form = useForm<FormValues>({
     resolver: zodResolver(formSchema),
@@ -66,6 +69,7 @@ export function CreateProfileButton() {
         description: values.description || "",
         github_login: values.githubLogin || "",
       },
+      message: siweMessage,
       signature,
     });
     await queryClient.invalidateQueries({ queryKey: ["profiles"] });
@@ -23,11 +23,14 @@ export function DeleteProfileDialog({ children }: DeleteProfileDialogProps) {
   const [open, setOpen] = useState(false);
   const deleteProfile = useDeleteProfile();
   const queryClient = useQueryClient();
-  const { address } = useAccount();
+  const { address, chainId } = useAccount();
   const { signMessageAsync } = useSignMessage();
   const { data: nonceData, isLoading: isLoadingNonce } = useGetNonce(address);
 
-  const siweMessage = nonceData ? generateSiweMessage(nonceData) : "";
+  const siweMessage =
+    address && chainId && nonceData
+      ? generateSiweMessage({ address, chainId, nonce: nonceData })
+      : "";
 
   const onConfirm = async () => {
     if (!siweMessage) {
@@ -35,7 +38,7 @@ export function DeleteProfileDialog({ children }: DeleteProfileDialogProps) {
     }
 
     const signature = await signMessageAsync({ message: siweMessage });
-    await deleteProfile.mutateAsync({ signature });
+    await deleteProfile.mutateAsync({ message: siweMessage, signature });
     await queryClient.invalidateQueries({ queryKey: ["profiles"] });
     setOpen(false);
   };
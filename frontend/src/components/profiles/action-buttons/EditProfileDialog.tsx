@@ -54,12 +54,19 @@ export function EditProfileDialog({
   const [open, setOpen] = useState(false);
   const updateProfile = useUpdateProfile();
   const queryClient = useQueryClient();
-  const { address: signerAddress } = useAccount();
+  const { address: signerAddress, chainId } = useAccount();
   const { signMessageAsync } = useSignMessage();
   const { data: nonceData, isLoading: isLoadingNonce } =
     useGetNonce(signerAddress);
 
-  const siweMessage = nonceData ? generateSiweMessage(nonceData) : "";
+  const siweMessage =
+    signerAddress && chainId && nonceData
+      ? generateSiweMessage({
+          address: signerAddress,
+          chainId,
+          nonce: nonceData,
+        })
+      : "";
 
   const form = useForm<FormValues>({
     resolver: zodResolver(formSchema),
@@ -95,6 +102,7 @@ export function EditProfileDialog({
           description: values.description || "",
           github_login: values.githubLogin || "",
         },
+        message: siweMessage,
         signature,
       });
       await queryClient.invalidateQueries({ queryKey: ["profiles"] });
@@ -9,10 +9,12 @@ import type {
   CreateProfileResponse,
 } from "@/lib/types/api";
 import { API_BASE_URL } from "@/lib/constants/apiConstants";
+import { encodeSiweMessage } from "@/lib/utils/siwe";
 
 async function postCreateProfile(
   input: CreateProfileInput,
   address: string,
+  message: string,
   signature: string
 ): Promise<CreateProfileResponse> {
   const response = await fetch(`${API_BASE_URL}/profiles/`, {
@@ -21,6 +23,7 @@ async function postCreateProfile(
       "Content-Type": "application/json",
       "x-eth-address": address,
       "x-eth-signature": signature,
+      "x-siwe-message": encodeSiweMessage(message),
     },
     body: JSON.stringify(input),
   });
@@ -44,6 +47,7 @@ async function postCreateProfile(
 
 type MutationVariables = {
   input: CreateProfileInput;
+  message: string;
   signature: string;
 };
 
@@ -57,14 +61,14 @@ export function useCreateProfile(): UseMutationResult<
 
   return useMutation<CreateProfileResponse, Error, MutationVariables>({
     mutationKey: ["create-profile"],
-    mutationFn: async ({ input, signature }) => {
+    mutationFn: async ({ input, message, signature }) => {
       if (!address) {
         throw new Error("Wallet not connected");
       }
-      return postCreateProfile(input, address, signature);
+      return postCreateProfile(input, address, message, signature);
     },
     onSuccess: () => {
-      // Invalidate nonce query since it was incremented
+      // Invalidate nonce query since the nonce was used up
       queryClient.invalidateQueries({ queryKey: ["nonce", address] });
     },
   });
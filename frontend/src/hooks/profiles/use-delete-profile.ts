@@ -5,10 +5,12 @@ import type {
   DeleteProfileResponse,
 } from "@/lib/types/api";
 import { API_BASE_URL } from "@/lib/constants/apiConstants";
+import { encodeSiweMessage } from "@/lib/utils/siwe";
 
 async function deleteProfile(
   address: string,
   signerAddress: string,
+  message: string,
   signature: string
 ): Promise<DeleteProfileResponse> {
   const response = await fetch(`${API_BASE_URL}/profiles/${address}`, {
@@ -17,6 +19,7 @@ async function deleteProfile(
       "Content-Type": "application/json",
       "x-eth-address": signerAddress,
       "x-eth-signature": signature,
+      "x-siwe-message": encodeSiweMessage(message),
     },
   });
 
@@ -37,6 +40,7 @@ async function deleteProfile(
 }
 
 type MutationVariables = {
+  message: string;
   signature: string;
 };
 
@@ -50,12 +54,12 @@ export function useDeleteProfile(): UseMutationResult<
 
   return useMutation<DeleteProfileResponse, Error, MutationVariables>({
     mutationKey: ["delete-profile"],
-    mutationFn: async ({ signature }) => {
+    mutationFn: async ({ message, signature }) => {
       if (!address) throw new Error("Wallet not connected");
-      return deleteProfile(address, address, signature);
+      return deleteProfile(address, address, message, signature);
     },
     onSuccess: () => {
-      // Invalidate nonce query since it was incremented
+      // Invalidate nonce query since the nonce was used up
       queryClient.invalidateQueries({ queryKey: ["nonce", address] });
     },
   });
@@ -19,8 +19,7 @@ async function getNonce(walletAddress: string): Promise<string> {
   }
 
   const data = await response.json();
-  // Convert nonce number to string for signing
-  return data.nonce?.toString() || data.toString();
+  return data.nonce;
 }
 
 export function useGetNonce(walletAddress: string | undefined) {
@@ -37,5 +36,7 @@ export function useGetNonce(walletAddress: string | undefined) {
     gcTime: 0,
     refetchOnMount: true,
     refetchOnWindowFocus: true,
+    // Nonces expire 5 minutes after being issued, so fetch a fresh one before then
+    refetchInterval: 4 * 60 * 1000,
   });
 }
\ No newline at end of file
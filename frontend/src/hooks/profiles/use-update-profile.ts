@@ -5,11 +5,13 @@ import type {
   UpdateProfileResponse,
 } from "@/lib/types/api";
 import { API_BASE_URL } from "@/lib/constants/apiConstants";
+import { encodeSiweMessage } from "@/lib/utils/siwe";
 
 async function putUpdateProfile(
   address: string,
   body: UpdateProfileInput,
   signerAddress: string,
+  message: string,
   signature: string
 ): Promise<UpdateProfileResponse> {
   const response = await fetch(`${API_BASE_URL}/profiles/${address}`, {
@@ -18,6 +20,7 @@ async function putUpdateProfile(
       "Content-Type": "application/json",
       "x-eth-address": signerAddress,
       "x-eth-signature": signature,
+      "x-siwe-message": encodeSiweMessage(message),
     },
     body: JSON.stringify(body),
   });
@@ -40,6 +43,7 @@ async function putUpdateProfile(
 
 type MutationVariables = {
   input: UpdateProfileInput;
+  message: string;
   signature: string;
 };
 
@@ -53,12 +57,12 @@ export function useUpdateProfile(): UseMutationResult<
 
   return useMutation<UpdateProfileResponse, Error, MutationVariables>({
     mutationKey: ["update-profile"],
-    mutationFn: async ({ input, signature }) => {
+    mutationFn: async ({ input, message, signature }) => {
       if (!address) throw new Error("Wallet not connected");
-      return putUpdateProfile(address, input, address, signature);
+      return putUpdateProfile(address, input, address, message, signature);
     },
     onSuccess: () => {
-      // Invalidate nonce query since it was incremented
+      // Invalidate nonce query since the nonce was used up
       queryClient.invalidateQueries({ queryKey: ["nonce", address] });
     },
   });
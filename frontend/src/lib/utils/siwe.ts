@@ -1,3 +1,34 @@
-export function generateSiweMessage(nonce: string): string {
-  return `Sign this message to authenticate with The Guild.\n\nNonce: ${nonce}`;
-}
\ No newline at end of file
+import { createSiweMessage } from "viem/siwe";
+
+// Sign-in messages are only accepted by the backend for a few minutes
+const MESSAGE_VALIDITY_MS = 5 * 60 * 1000;
+
+type SiweMessageParams = {
+  address: `0x${string}`;
+  chainId: number;
+  nonce: string;
+};
+
+export function generateSiweMessage({
+  address,
+  chainId,
+  nonce,
+}: SiweMessageParams): string {
+  const issuedAt = new Date();
+  return createSiweMessage({
+    domain: window.location.host,
+    address,
+    statement: "Sign in to The Guild.",
+    uri: window.location.origin,
+    version: "1",
+    chainId,
+    nonce,
+    issuedAt,
+    expirationTime: new Date(issuedAt.getTime() + MESSAGE_VALIDITY_MS),
+  });
+}
+
+// The message spans several lines, which HTTP headers cannot carry as is
+export function encodeSiweMessage(message: string): string {
+  return btoa(message);
+}
@@ -0,0 +1,5 @@
+CREATE TABLE block_cursors (
+    contract VARCHAR(255) PRIMARY KEY,
+    last_indexed_block BIGINT NOT NULL,
+    updated_at TIMESTAMP WITH TIME ZONE NOT NULL DEFAULT NOW()
+);
@@ -0,0 +1,12 @@
+ALTER TABLE ethereum_events
+    ADD COLUMN block_number BIGINT NOT NULL DEFAULT 0,
+    ADD COLUMN block_hash VARCHAR(66) NOT NULL DEFAULT '';
+
+CREATE INDEX idx_ethereum_events_block_number ON ethereum_events(block_number);
+
+CREATE TABLE indexed_blocks (
+    block_number BIGINT PRIMARY KEY,
+    block_hash VARCHAR(66) NOT NULL,
+    parent_hash VARCHAR(66) NOT NULL,
+    created_at TIMESTAMP WITH TIME ZONE NOT NULL DEFAULT NOW()
+);
@@ -0,0 +1,11 @@
+CREATE TABLE badges (
+    name VARCHAR(64) PRIMARY KEY,
+    description TEXT NOT NULL,
+    creator VARCHAR(42) NOT NULL,
+    event_id VARCHAR(255) NOT NULL REFERENCES ethereum_events(id) ON DELETE CASCADE,
+    block_number BIGINT NOT NULL,
+    timestamp TIMESTAMP WITH TIME ZONE NOT NULL,
+    created_at TIMESTAMP WITH TIME ZONE NOT NULL DEFAULT NOW()
+);
+
+CREATE INDEX idx_badges_creator ON badges(creator);
@@ -0,0 +1,17 @@
+CREATE TABLE attestations (
+    uid VARCHAR(66) PRIMARY KEY,
+    attester VARCHAR(42) NOT NULL,
+    recipient VARCHAR(42) NOT NULL,
+    badge_name VARCHAR(64) NOT NULL,
+    justification TEXT NOT NULL,
+    time TIMESTAMP WITH TIME ZONE NOT NULL,
+    revocation_time TIMESTAMP WITH TIME ZONE,
+    revocation_block_number BIGINT,
+    event_id VARCHAR(255) NOT NULL REFERENCES ethereum_events(id) ON DELETE CASCADE,
+    block_number BIGINT NOT NULL,
+    created_at TIMESTAMP WITH TIME ZONE NOT NULL DEFAULT NOW()
+);
+
+CREATE INDEX idx_attestations_attester ON attestations(attester);
+CREATE INDEX idx_attestations_recipient ON attestations(recipient);
+CREATE INDEX idx_attestations_badge_name ON attestations(badge_name);
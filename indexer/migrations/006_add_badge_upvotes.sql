@@ -0,0 +1,20 @@
+CREATE TABLE badge_upvotes (
+    badge_name VARCHAR(64) NOT NULL,
+    voter VARCHAR(42) NOT NULL,
+    event_id VARCHAR(255) NOT NULL REFERENCES ethereum_events(id) ON DELETE CASCADE,
+    block_number BIGINT NOT NULL,
+    timestamp TIMESTAMP WITH TIME ZONE NOT NULL,
+    created_at TIMESTAMP WITH TIME ZONE NOT NULL DEFAULT NOW(),
+    PRIMARY KEY (badge_name, voter)
+);
+
+CREATE INDEX idx_badge_upvotes_voter ON badge_upvotes(voter);
+CREATE INDEX idx_badge_upvotes_block_number ON badge_upvotes(block_number);
+
+CREATE TABLE badge_vote_counts (
+    badge_name VARCHAR(64) PRIMARY KEY,
+    upvotes BIGINT NOT NULL DEFAULT 0,
+    updated_at TIMESTAMP WITH TIME ZONE NOT NULL DEFAULT NOW()
+);
+
+CREATE INDEX idx_badge_vote_counts_upvotes ON badge_vote_counts(upvotes);
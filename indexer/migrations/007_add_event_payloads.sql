@@ -0,0 +1,14 @@
+-- Earlier rows only hold a Debug string of the event that cannot be decoded,
+-- so they are dropped and their contracts re-indexed from the start block.
+DELETE FROM ethereum_events;
+DELETE FROM block_cursors;
+
+ALTER TABLE ethereum_events
+    ALTER COLUMN event_type TYPE VARCHAR(64),
+    ADD COLUMN payload JSONB NOT NULL,
+    ADD COLUMN tx_hash VARCHAR(66) NOT NULL,
+    ADD COLUMN log_index BIGINT NOT NULL;
+
+CREATE INDEX idx_ethereum_events_event_type ON ethereum_events(event_type);
+CREATE INDEX idx_ethereum_events_tx_hash ON ethereum_events(tx_hash);
+CREATE INDEX idx_ethereum_events_payload ON ethereum_events USING GIN (payload);
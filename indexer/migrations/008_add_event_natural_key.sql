@@ -0,0 +1,6 @@
+-- Events are identified by where they were emitted, so re-ingesting a log is a no-op.
+ALTER TABLE ethereum_events
+    ADD COLUMN chain_id BIGINT NOT NULL;
+
+ALTER TABLE ethereum_events
+    ADD CONSTRAINT uq_ethereum_events_chain_tx_log UNIQUE (chain_id, tx_hash, log_index);
@@ -0,0 +1,50 @@
+-- Several chains are indexed side by side, so cursors, headers and projections are keyed by chain.
+-- Until now a single chain was indexed: existing rows belong to the chain of the stored events.
+-- Cursors and headers of a database without events cannot be attributed and are reset.
+ALTER TABLE block_cursors ADD COLUMN chain_id BIGINT;
+UPDATE block_cursors SET chain_id = (SELECT chain_id FROM ethereum_events LIMIT 1);
+DELETE FROM block_cursors WHERE chain_id IS NULL;
+ALTER TABLE block_cursors
+    ALTER COLUMN chain_id SET NOT NULL,
+    DROP CONSTRAINT block_cursors_pkey,
+    ADD PRIMARY KEY (chain_id, contract);
+
+ALTER TABLE indexed_blocks ADD COLUMN chain_id BIGINT;
+UPDATE indexed_blocks SET chain_id = (SELECT chain_id FROM ethereum_events LIMIT 1);
+DELETE FROM indexed_blocks WHERE chain_id IS NULL;
+ALTER TABLE indexed_blocks
+    ALTER COLUMN chain_id SET NOT NULL,
+    DROP CONSTRAINT indexed_blocks_pkey,
+    ADD PRIMARY KEY (chain_id, block_number);
+
+ALTER TABLE badges ADD COLUMN chain_id BIGINT;
+UPDATE badges SET chain_id = e.chain_id FROM ethereum_events e WHERE e.id = badges.event_id;
+ALTER TABLE badges
+    ALTER COLUMN chain_id SET NOT NULL,
+    DROP CONSTRAINT badges_pkey,
+    ADD PRIMARY KEY (chain_id, name);
+
+ALTER TABLE attestations ADD COLUMN chain_id BIGINT;
+UPDATE attestations SET chain_id = e.chain_id FROM ethereum_events e WHERE e.id = attestations.event_id;
+ALTER TABLE attestations
+    ALTER COLUMN chain_id SET NOT NULL,
+    DROP CONSTRAINT attestations_pkey,
+    ADD PRIMARY KEY (chain_id, uid);
+
+ALTER TABLE badge_upvotes ADD COLUMN chain_id BIGINT;
+UPDATE badge_upvotes SET chain_id = e.chain_id FROM ethereum_events e WHERE e.id = badge_upvotes.event_id;
+ALTER TABLE badge_upvotes
+    ALTER COLUMN chain_id SET NOT NULL,
+    DROP CONSTRAINT badge_upvotes_pkey,
+    ADD PRIMARY KEY (chain_id, badge_name, voter);
+
+-- Vote counts have no event to take the chain from, so they are rebuilt from the upvotes.
+DELETE FROM badge_vote_counts;
+ALTER TABLE badge_vote_counts
+    ADD COLUMN chain_id BIGINT NOT NULL,
+    DROP CONSTRAINT badge_vote_counts_pkey,
+    ADD PRIMARY KEY (chain_id, badge_name);
+INSERT INTO badge_vote_counts (chain_id, badge_name, upvotes)
+SELECT chain_id, badge_name, COUNT(*) FROM badge_upvotes GROUP BY chain_id, badge_name;
+
+CREATE INDEX idx_ethereum_events_chain_block ON ethereum_events(chain_id, block_number);
@@ -0,0 +1,16 @@
+CREATE TABLE token_balances (
+    chain_id BIGINT NOT NULL,
+    address VARCHAR(42) NOT NULL,
+    balance NUMERIC(78, 0) NOT NULL DEFAULT 0,
+    updated_at TIMESTAMP WITH TIME ZONE NOT NULL DEFAULT NOW(),
+    PRIMARY KEY (chain_id, address)
+);
+
+CREATE INDEX idx_token_balances_balance ON token_balances(chain_id, balance DESC);
+
+-- Only mints were ingested so far; later transfers are picked up by backfilling the activity token.
+INSERT INTO token_balances (chain_id, address, balance)
+SELECT chain_id, payload->>'recipient', SUM((payload->>'amount')::numeric)
+FROM ethereum_events
+WHERE event_type = 'ActivityTokenMinted'
+GROUP BY chain_id, payload->>'recipient';
@@ -0,0 +1,9 @@
+-- Events stay pending until the chain's confirmation depth is mined on top of them.
+-- Events indexed before confirmation depths existed are taken as final.
+ALTER TABLE ethereum_events
+    ADD COLUMN status VARCHAR(16) NOT NULL DEFAULT 'finalized'
+    CHECK (status IN ('pending', 'finalized'));
+ALTER TABLE ethereum_events ALTER COLUMN status DROP DEFAULT;
+
+CREATE INDEX idx_ethereum_events_pending ON ethereum_events(chain_id, block_number)
+    WHERE status = 'pending';
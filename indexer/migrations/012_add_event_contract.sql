@@ -0,0 +1,13 @@
+-- Name of the configured contract that emitted each event, so that a single contract can be rewound.
+-- Events indexed before are attributed by their type, every type coming from a single contract.
+ALTER TABLE ethereum_events ADD COLUMN contract VARCHAR(64);
+UPDATE ethereum_events SET contract = CASE
+    WHEN event_type IN ('ActivityTokenMinted', 'ActivityTokenTransferred') THEN 'activity_token'
+    WHEN event_type = 'BadgeCreated' THEN 'badge_registry'
+    WHEN event_type = 'BadgeUpvoted' THEN 'badge_ranking'
+    WHEN event_type IN ('AttestationCreated', 'AttestationRevoked') THEN 'eas'
+    ELSE payload->>'contract'
+END;
+ALTER TABLE ethereum_events ALTER COLUMN contract SET NOT NULL;
+
+CREATE INDEX idx_ethereum_events_contract_block ON ethereum_events(chain_id, contract, block_number);
@@ -0,0 +1,7 @@
+-- Contracts whose ingestion an operator paused, skipped by the polling loops until resumed.
+CREATE TABLE paused_contracts (
+    chain_id BIGINT NOT NULL,
+    contract VARCHAR(64) NOT NULL,
+    paused_at TIMESTAMP WITH TIME ZONE NOT NULL DEFAULT NOW(),
+    PRIMARY KEY (chain_id, contract)
+);
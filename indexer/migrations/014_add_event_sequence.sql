@@ -0,0 +1,20 @@
+-- Events are numbered in the order they are committed, which streams resume from: block
+-- positions of different chains and contracts do not tell what a client has not seen yet.
+-- Events indexed before are numbered in the order they were ingested.
+ALTER TABLE ethereum_events ADD COLUMN sequence BIGINT;
+CREATE SEQUENCE ethereum_events_sequence_seq OWNED BY ethereum_events.sequence;
+
+UPDATE ethereum_events
+SET sequence = numbered.sequence
+FROM (
+    SELECT id, ROW_NUMBER() OVER (ORDER BY created_at, chain_id, block_number, log_index) AS sequence
+    FROM ethereum_events
+) AS numbered
+WHERE ethereum_events.id = numbered.id;
+SELECT setval('ethereum_events_sequence_seq', COALESCE(MAX(sequence), 0) + 1, false)
+FROM ethereum_events;
+
+ALTER TABLE ethereum_events
+    ALTER COLUMN sequence SET DEFAULT nextval('ethereum_events_sequence_seq'),
+    ALTER COLUMN sequence SET NOT NULL;
+CREATE UNIQUE INDEX idx_ethereum_events_sequence ON ethereum_events(sequence);
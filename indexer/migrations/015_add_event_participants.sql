@@ -0,0 +1,25 @@
+-- Events are listed by recipient and creator whatever field their type stores them in, so both
+-- are kept in indexed columns. Transfers are received by `to`, and attestations are created by
+-- their attester.
+ALTER TABLE ethereum_events
+    ADD COLUMN recipient VARCHAR(42),
+    ADD COLUMN creator VARCHAR(42);
+
+UPDATE ethereum_events
+SET recipient = CASE event_type
+        WHEN 'ActivityTokenMinted' THEN payload->>'recipient'
+        WHEN 'ActivityTokenTransferred' THEN payload->>'to'
+        WHEN 'AttestationCreated' THEN payload->>'recipient'
+        WHEN 'AttestationRevoked' THEN payload->>'recipient'
+    END,
+    creator = CASE event_type
+        WHEN 'BadgeCreated' THEN payload->>'creator'
+        WHEN 'AttestationCreated' THEN payload->>'attester'
+        WHEN 'AttestationRevoked' THEN payload->>'attester'
+    END;
+
+CREATE INDEX idx_ethereum_events_position ON ethereum_events(block_number, log_index, id);
+CREATE INDEX idx_ethereum_events_recipient ON ethereum_events(recipient, block_number, log_index, id)
+    WHERE recipient IS NOT NULL;
+CREATE INDEX idx_ethereum_events_creator ON ethereum_events(creator, block_number, log_index, id)
+    WHERE creator IS NOT NULL;
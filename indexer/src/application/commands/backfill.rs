@@ -0,0 +1,172 @@
+use std::{collections::HashMap, error::Error, sync::Arc};
+
+use crate::{
+    application::commands::handle_reorg::TRACKED_BLOCK_HEADERS,
+    domain::{
+        entities::{
+            block_cursor::BlockCursor, block_header::BlockHeader, ethereum_event::EventStatus,
+            indexed_contract::IndexedContract,
+        },
+        repositories::{
+            block_cursor_repository::BlockCursorRepository,
+            block_header_repository::BlockHeaderRepository,
+            ethereum_event_repository::EthereumEventRepository,
+        },
+        services::ethereum_event_polling_service::{
+            BlockRangeTooLarge, EthereumEventPollingService,
+        },
+    },
+};
+
+/// Ingests the events emitted by the named contracts, or every indexed contract when
+/// `contract_names` is empty, between `from_block` and `to_block` inclusive.
+///
+/// Logs are fetched in chunks of at most `max_batch_size` blocks, halved whenever the
+/// provider rejects a range for holding too many logs and grown back after each success.
+/// Ingestion is idempotent, so ranges that were already indexed can be backfilled again.
+/// The header of the last block of each chunk is stored as the live poll does, so that reorgs
+/// of backfilled ranges are detected too.
+#[allow(clippy::too_many_arguments)]
+pub async fn backfill(
+    ethereum_event_polling_service: Arc<dyn EthereumEventPollingService>,
+    ethereum_event_repository: Arc<dyn EthereumEventRepository>,
+    block_cursor_repository: Arc<dyn BlockCursorRepository>,
+    block_header_repository: Arc<dyn BlockHeaderRepository>,
+    contract_names: &[String],
+    from_block: u64,
+    to_block: u64,
+    max_batch_size: u64,
+) -> Result<usize, Box<dyn Error>> {
+    if from_block > to_block {
+        return Err(format!("Invalid block range {from_block}..{to_block}").into());
+    }
+    let latest_block = ethereum_event_polling_service.latest_block_number().await?;
+    if to_block > latest_block {
+        return Err(format!("Block {to_block} is past the chain head {latest_block}").into());
+    }
+
+    let chain_id = ethereum_event_polling_service.chain_id();
+    let contracts = ethereum_event_polling_service.contracts();
+    for name in contract_names {
+        if !contracts.iter().any(|contract| &contract.name == name) {
+            return Err(format!("Contract {name} is not configured").into());
+        }
+    }
+
+    let mut headers = HashMap::new();
+    let mut events_ingested = 0;
+    for contract in contracts
+        .iter()
+        .filter(|contract| contract_names.is_empty() || contract_names.contains(&contract.name))
+    {
+        events_ingested += backfill_contract(
+            ethereum_event_polling_service.clone(),
+            ethereum_event_repository.clone(),
+            block_header_repository.as_ref(),
+            &mut headers,
+            contract,
+            from_block,
+            to_block,
+            latest_block,
+            max_batch_size.max(1),
+        )
+        .await?;
+
+        // Hand over to the live loop when the backfilled range continues the cursor,
+        // without moving an existing cursor backwards or past a gap.
+        let cursor = block_cursor_repository
+            .find_by_contract(chain_id, &contract.name)
+            .await?;
+        let continues_cursor = cursor.as_ref().is_none_or(|cursor| {
+            cursor.next_block() >= from_block && cursor.last_indexed_block < to_block
+        });
+        if continues_cursor {
+            block_cursor_repository
+                .save(&BlockCursor::new(chain_id, contract.name.clone(), to_block))
+                .await?;
+        }
+    }
+    block_header_repository
+        .prune(chain_id, TRACKED_BLOCK_HEADERS)
+        .await?;
+
+    Ok(events_ingested)
+}
+
+/// Backfills a single contract, caching in `headers` the block headers fetched so that chunks
+/// of other contracts ending on the same block reuse them.
+#[allow(clippy::too_many_arguments)]
+async fn backfill_contract(
+    ethereum_event_polling_service: Arc<dyn EthereumEventPollingService>,
+    ethereum_event_repository: Arc<dyn EthereumEventRepository>,
+    block_header_repository: &dyn BlockHeaderRepository,
+    headers: &mut HashMap<u64, BlockHeader>,
+    contract: &IndexedContract,
+    from_block: u64,
+    to_block: u64,
+    latest_block: u64,
+    max_batch_size: u64,
+) -> Result<usize, Box<dyn Error>> {
+    let chain_id = ethereum_event_polling_service.chain_id();
+    let confirmation_blocks = ethereum_event_polling_service.confirmation_blocks();
+    let mut batch_size = max_batch_size;
+    let mut next_block = from_block;
+    let mut events_ingested = 0;
+
+    while next_block <= to_block {
+        let chunk_end = to_block.min(next_block + batch_size - 1);
+        // Fetched before the logs so that a fork happening in between is caught by the next poll.
+        let header = match headers.get(&chunk_end) {
+            Some(header) => header.clone(),
+            None => {
+                let header = ethereum_event_polling_service
+                    .block_header(chunk_end)
+                    .await?
+                    .ok_or_else(|| format!("Block {chunk_end} not found"))?;
+                headers.insert(chunk_end, header.clone());
+                header
+            }
+        };
+        let polled = ethereum_event_polling_service
+            .poll(contract, next_block, chunk_end)
+            .await;
+        let mut events = match polled {
+            Ok(events) => events,
+            Err(e) if e.is::<BlockRangeTooLarge>() && batch_size > 1 => {
+                batch_size /= 2;
+                tracing::debug!(
+                    "Range {}..{} too large for {}, retrying with {} blocks",
+                    next_block,
+                    chunk_end,
+                    contract.name,
+                    batch_size
+                );
+                continue;
+            }
+            Err(e) => return Err(e),
+        };
+
+        for event in &mut events {
+            event.status = EventStatus::at(event.block_number, latest_block, confirmation_blocks);
+        }
+        events_ingested += ethereum_event_repository.insert_many(events).await?.len();
+        block_header_repository.save(chain_id, &header).await?;
+
+        let done = chunk_end - from_block + 1;
+        let total = to_block - from_block + 1;
+        tracing::info!(
+            "Backfilled {} up to block {} ({}/{} blocks, {:.1}%, {} events)",
+            contract.name,
+            chunk_end,
+            done,
+            total,
+            done as f64 * 100.0 / total as f64,
+            events_ingested
+        );
+
+        next_block = chunk_end + 1;
+        batch_size = max_batch_size.min(batch_size * 2);
+    }
+
+    Ok(events_ingested)
+}
@@ -0,0 +1,90 @@
+use std::{error::Error, sync::Arc};
+
+use crate::domain::{
+    entities::block_header::BlockHeader,
+    repositories::{
+        block_cursor_repository::BlockCursorRepository,
+        block_header_repository::BlockHeaderRepository,
+        ethereum_event_repository::EthereumEventRepository,
+    },
+    services::ethereum_event_polling_service::EthereumEventPollingService,
+};
+
+/// Number of ingested block headers kept around to find a common ancestor after a fork.
+pub const TRACKED_BLOCK_HEADERS: i64 = 256;
+
+/// Checks the stored tip of the service's chain against the canonical chain. When it was
+/// reorged away, walks back the stored headers to the most recent one that is still
+/// canonical, rolls back the chain's events and cursors above it and returns that common
+/// ancestor. Other chains are left untouched.
+pub async fn handle_reorg(
+    ethereum_event_polling_service: Arc<dyn EthereumEventPollingService>,
+    ethereum_event_repository: Arc<dyn EthereumEventRepository>,
+    block_cursor_repository: Arc<dyn BlockCursorRepository>,
+    block_header_repository: Arc<dyn BlockHeaderRepository>,
+) -> Result<Option<u64>, Box<dyn Error>> {
+    let chain_id = ethereum_event_polling_service.chain_id();
+    let headers = block_header_repository
+        .list_recent(chain_id, TRACKED_BLOCK_HEADERS)
+        .await?;
+    let Some(tip) = headers.first() else {
+        return Ok(None);
+    };
+    if is_canonical(ethereum_event_polling_service.as_ref(), tip).await? {
+        return Ok(None);
+    }
+
+    let mut common_ancestor = None;
+    for header in headers.iter().skip(1) {
+        if is_canonical(ethereum_event_polling_service.as_ref(), header).await? {
+            common_ancestor = Some(header.number);
+            break;
+        }
+    }
+    let common_ancestor = match common_ancestor {
+        Some(block_number) => block_number,
+        None => {
+            // Every tracked header is gone: the fork is deeper than our history, so
+            // re-index from just below the oldest block we know about.
+            let oldest = headers.last().map(|h| h.number).unwrap_or(tip.number);
+            tracing::warn!(
+                "Reorg on chain {} deeper than {} tracked blocks, rolling back to block {}",
+                chain_id,
+                headers.len(),
+                oldest.saturating_sub(1)
+            );
+            oldest.saturating_sub(1)
+        }
+    };
+
+    tracing::warn!(
+        "Reorg detected on chain {} at block {} ({}), rolling back to block {}",
+        chain_id,
+        tip.number,
+        tip.hash,
+        common_ancestor
+    );
+
+    // Headers go last so that an interrupted rollback is detected and resumed on the next poll.
+    ethereum_event_repository
+        .delete_above_block(chain_id, common_ancestor, None)
+        .await?;
+    block_cursor_repository
+        .rewind(chain_id, common_ancestor)
+        .await?;
+    block_header_repository
+        .delete_above(chain_id, common_ancestor)
+        .await?;
+
+    Ok(Some(common_ancestor))
+}
+
+async fn is_canonical(
+    ethereum_event_polling_service: &dyn EthereumEventPollingService,
+    block_header: &BlockHeader,
+) -> Result<bool, Box<dyn Error>> {
+    Ok(ethereum_event_polling_service
+        .block_header(block_header.number)
+        .await?
+        .is_some_and(|canonical| canonical.hash == block_header.hash))
+}
@@ -1 +1,6 @@
+pub mod backfill;
+pub mod handle_reorg;
 pub mod poll;
+pub mod poll_loop;
+pub mod rewind_contract;
+pub mod set_contract_paused;
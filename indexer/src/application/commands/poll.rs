@@ -1,14 +1,178 @@
-use std::{error::Error, sync::Arc};
+use std::{collections::HashMap, error::Error, sync::Arc};
 
-use crate::domain::{
-    repositories::ethereum_event_repository::EthereumEventRepository,
-    services::ethereum_event_polling_service::EthereumEventPollingService,
+use crate::{
+    application::commands::handle_reorg::{TRACKED_BLOCK_HEADERS, handle_reorg},
+    domain::{
+        entities::{
+            block_cursor::BlockCursor, block_header::BlockHeader, ethereum_event::EventStatus,
+        },
+        repositories::{
+            block_cursor_repository::BlockCursorRepository,
+            block_header_repository::BlockHeaderRepository,
+            ethereum_event_repository::EthereumEventRepository,
+        },
+        services::{
+            ethereum_event_broadcaster::EthereumEventBroadcaster,
+            ethereum_event_polling_service::EthereumEventPollingService,
+            indexer_metrics::IndexerMetrics, ingestion_control::IngestionControl,
+        },
+    },
 };
 
+/// Outcome of a single polling pass over every contract indexed on a chain.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct PollResult {
+    pub events_ingested: usize,
+    /// True when at least one contract's cursor is still behind the chain head.
+    pub behind: bool,
+    /// Common ancestor the indexer rolled back to, if a reorg was detected.
+    pub reorg_ancestor: Option<u64>,
+}
+
+/// Advances each contract's cursor by at most `batch_size` blocks, ingesting the events found in
+/// that range and broadcasting those that were new. Contracts without a cursor start at their
+/// configured start block, or at the chain head when they have none, and paused contracts are
+/// skipped.
+/// Events are finalized once the chain's confirmation depth has been mined on top of them.
+/// Progress, ingested events and reorgs are recorded in the metrics, and the throughput and
+/// errors of each contract in the ingestion control.
+#[allow(clippy::too_many_arguments)]
 pub async fn poll(
     ethereum_event_polling_service: Arc<dyn EthereumEventPollingService>,
     ethereum_event_repository: Arc<dyn EthereumEventRepository>,
-) -> Result<(), Box<dyn Error>> {
-    let poll_result = ethereum_event_polling_service.poll().await?;
-    ethereum_event_repository.insert_many(poll_result).await
+    block_cursor_repository: Arc<dyn BlockCursorRepository>,
+    block_header_repository: Arc<dyn BlockHeaderRepository>,
+    ethereum_event_broadcaster: Arc<dyn EthereumEventBroadcaster>,
+    indexer_metrics: Arc<dyn IndexerMetrics>,
+    ingestion_control: Arc<dyn IngestionControl>,
+    batch_size: u64,
+) -> Result<PollResult, Box<dyn Error>> {
+    let chain_id = ethereum_event_polling_service.chain_id();
+    // Admin operations wait for the pass to end rather than race with its writes.
+    let _pass = ingestion_control.lock_chain(chain_id).await;
+    let mut current_contract = None;
+    let result = poll_chain(
+        ethereum_event_polling_service,
+        ethereum_event_repository,
+        block_cursor_repository,
+        block_header_repository,
+        ethereum_event_broadcaster,
+        indexer_metrics,
+        ingestion_control.as_ref(),
+        batch_size,
+        &mut current_contract,
+    )
+    .await;
+    if let Err(e) = &result {
+        ingestion_control.record_error(chain_id, current_contract.as_deref(), &e.to_string());
+    }
+    result
+}
+
+/// Polling pass itself, keeping `current_contract` on the contract being polled so that an
+/// error can be blamed on it.
+#[allow(clippy::too_many_arguments)]
+async fn poll_chain(
+    ethereum_event_polling_service: Arc<dyn EthereumEventPollingService>,
+    ethereum_event_repository: Arc<dyn EthereumEventRepository>,
+    block_cursor_repository: Arc<dyn BlockCursorRepository>,
+    block_header_repository: Arc<dyn BlockHeaderRepository>,
+    ethereum_event_broadcaster: Arc<dyn EthereumEventBroadcaster>,
+    indexer_metrics: Arc<dyn IndexerMetrics>,
+    ingestion_control: &dyn IngestionControl,
+    batch_size: u64,
+    current_contract: &mut Option<String>,
+) -> Result<PollResult, Box<dyn Error>> {
+    let mut result = PollResult {
+        reorg_ancestor: handle_reorg(
+            ethereum_event_polling_service.clone(),
+            ethereum_event_repository.clone(),
+            block_cursor_repository.clone(),
+            block_header_repository.clone(),
+        )
+        .await?,
+        ..Default::default()
+    };
+
+    let chain_id = ethereum_event_polling_service.chain_id();
+    if result.reorg_ancestor.is_some() {
+        indexer_metrics.record_reorg(chain_id);
+    }
+    let latest_block = ethereum_event_polling_service.latest_block_number().await?;
+    let confirmation_blocks = ethereum_event_polling_service.confirmation_blocks();
+    let mut headers: HashMap<u64, BlockHeader> = HashMap::new();
+    let paused = block_cursor_repository.list_paused(chain_id).await?;
+
+    for contract in ethereum_event_polling_service.contracts() {
+        if paused.contains(&contract.name) {
+            continue;
+        }
+        *current_contract = Some(contract.name.clone());
+        let from_block = block_cursor_repository
+            .find_by_contract(chain_id, &contract.name)
+            .await?
+            .map(|cursor| cursor.next_block())
+            .or(contract.start_block)
+            .unwrap_or(latest_block);
+        if from_block > latest_block {
+            indexer_metrics.record_contract_progress(
+                chain_id,
+                &contract.name,
+                from_block.saturating_sub(1),
+                latest_block,
+            );
+            continue;
+        }
+        let to_block = latest_block.min(from_block + batch_size.max(1) - 1);
+
+        // Fetched before the logs so that a fork happening in between is caught by the next poll.
+        let header = match headers.get(&to_block) {
+            Some(header) => header.clone(),
+            None => {
+                let header = ethereum_event_polling_service
+                    .block_header(to_block)
+                    .await?
+                    .ok_or_else(|| format!("Block {to_block} not found"))?;
+                headers.insert(to_block, header.clone());
+                header
+            }
+        };
+
+        let mut events = ethereum_event_polling_service
+            .poll(&contract, from_block, to_block)
+            .await?;
+        for event in &mut events {
+            event.status = EventStatus::at(event.block_number, latest_block, confirmation_blocks);
+        }
+        let inserted = ethereum_event_repository.insert_many(events).await?;
+        result.events_ingested += inserted.len();
+        indexer_metrics.record_events_ingested(chain_id, &inserted);
+        ethereum_event_broadcaster.publish(&inserted);
+        block_header_repository.save(chain_id, &header).await?;
+        block_cursor_repository
+            .save(&BlockCursor::new(chain_id, contract.name.clone(), to_block))
+            .await?;
+        indexer_metrics.record_contract_progress(chain_id, &contract.name, to_block, latest_block);
+        ingestion_control.record_progress(
+            chain_id,
+            &contract.name,
+            to_block - from_block + 1,
+            inserted.len(),
+        );
+
+        result.behind |= to_block < latest_block;
+    }
+    *current_contract = None;
+
+    // Events ingested by earlier polls are promoted once the head has moved far enough past them.
+    if let Some(final_block) = latest_block.checked_sub(confirmation_blocks) {
+        ethereum_event_repository
+            .finalize_up_to(chain_id, final_block)
+            .await?;
+    }
+    block_header_repository
+        .prune(chain_id, TRACKED_BLOCK_HEADERS)
+        .await?;
+
+    Ok(result)
 }
@@ -0,0 +1,71 @@
+use std::{sync::Arc, time::Duration};
+
+use crate::{
+    application::commands::poll::poll,
+    domain::{
+        repositories::{
+            block_cursor_repository::BlockCursorRepository,
+            block_header_repository::BlockHeaderRepository,
+            ethereum_event_repository::EthereumEventRepository,
+        },
+        services::{
+            ethereum_event_broadcaster::EthereumEventBroadcaster,
+            ethereum_event_polling_service::EthereumEventPollingService,
+            indexer_metrics::IndexerMetrics, ingestion_control::IngestionControl,
+        },
+    },
+};
+
+/// Polls forever, waiting for new events or at most `poll_interval` once every contract
+/// has caught up with the chain head or after a failed pass.
+#[allow(clippy::too_many_arguments)]
+pub async fn poll_loop(
+    ethereum_event_polling_service: Arc<dyn EthereumEventPollingService>,
+    ethereum_event_repository: Arc<dyn EthereumEventRepository>,
+    block_cursor_repository: Arc<dyn BlockCursorRepository>,
+    block_header_repository: Arc<dyn BlockHeaderRepository>,
+    ethereum_event_broadcaster: Arc<dyn EthereumEventBroadcaster>,
+    indexer_metrics: Arc<dyn IndexerMetrics>,
+    ingestion_control: Arc<dyn IngestionControl>,
+    batch_size: u64,
+    poll_interval: Duration,
+) {
+    loop {
+        let behind = match poll(
+            ethereum_event_polling_service.clone(),
+            ethereum_event_repository.clone(),
+            block_cursor_repository.clone(),
+            block_header_repository.clone(),
+            ethereum_event_broadcaster.clone(),
+            indexer_metrics.clone(),
+            ingestion_control.clone(),
+            batch_size,
+        )
+        .await
+        {
+            Ok(result) => {
+                tracing::debug!(
+                    "Ingested {} events on chain {}, behind: {}",
+                    result.events_ingested,
+                    ethereum_event_polling_service.chain_id(),
+                    result.behind
+                );
+                result.behind
+            }
+            Err(e) => {
+                tracing::error!(
+                    "Polling chain {} failed: {}",
+                    ethereum_event_polling_service.chain_id(),
+                    e
+                );
+                false
+            }
+        };
+
+        if !behind {
+            ethereum_event_polling_service
+                .wait_for_events(poll_interval)
+                .await;
+        }
+    }
+}
@@ -0,0 +1,70 @@
+use std::{error::Error, fmt, sync::Arc};
+
+use crate::domain::{
+    entities::block_cursor::BlockCursor,
+    repositories::{
+        block_cursor_repository::BlockCursorRepository,
+        ethereum_event_repository::EthereumEventRepository,
+    },
+    services::ingestion_control::IngestionControl,
+};
+
+/// Rewind that would move a contract's cursor forward, or that targets a contract not ingested yet.
+#[derive(Debug)]
+pub struct InvalidRewind(pub String);
+
+impl fmt::Display for InvalidRewind {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "Invalid rewind: {}", self.0)
+    }
+}
+
+impl Error for InvalidRewind {}
+
+/// Moves the cursor of a contract back to `block_number` and deletes its events above that
+/// block along with the rows derived from them, so that they are ingested again by the next
+/// polling passes. Other contracts of the chain are left untouched.
+pub async fn rewind_contract(
+    ethereum_event_repository: Arc<dyn EthereumEventRepository>,
+    block_cursor_repository: Arc<dyn BlockCursorRepository>,
+    ingestion_control: Arc<dyn IngestionControl>,
+    chain_id: u64,
+    contract: &str,
+    block_number: u64,
+) -> Result<(), Box<dyn Error>> {
+    let _operation = ingestion_control.lock_chain(chain_id).await;
+    let cursor = block_cursor_repository
+        .find_by_contract(chain_id, contract)
+        .await?
+        .ok_or_else(|| InvalidRewind(format!("{contract} has not been ingested yet")))?;
+    if block_number > cursor.last_indexed_block {
+        return Err(InvalidRewind(format!(
+            "{contract} is only ingested up to block {}",
+            cursor.last_indexed_block
+        ))
+        .into());
+    }
+
+    tracing::warn!(
+        "Rewinding {} on chain {} from block {} to block {}",
+        contract,
+        chain_id,
+        cursor.last_indexed_block,
+        block_number
+    );
+
+    // The cursor goes first so that an interrupted rewind re-ingests the events left behind
+    // rather than skip over deleted ones.
+    block_cursor_repository
+        .save(&BlockCursor::new(
+            chain_id,
+            contract.to_string(),
+            block_number,
+        ))
+        .await?;
+    ethereum_event_repository
+        .delete_above_block(chain_id, block_number, Some(contract))
+        .await?;
+
+    Ok(())
+}
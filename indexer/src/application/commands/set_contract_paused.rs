@@ -0,0 +1,28 @@
+use std::{error::Error, sync::Arc};
+
+use crate::domain::{
+    repositories::block_cursor_repository::BlockCursorRepository,
+    services::ingestion_control::IngestionControl,
+};
+
+/// Pauses or resumes the ingestion of a contract. A running polling pass of the chain is waited
+/// for, so no batch of the contract is ingested once this returns.
+pub async fn set_contract_paused(
+    block_cursor_repository: Arc<dyn BlockCursorRepository>,
+    ingestion_control: Arc<dyn IngestionControl>,
+    chain_id: u64,
+    contract: &str,
+    paused: bool,
+) -> Result<(), Box<dyn Error>> {
+    let _operation = ingestion_control.lock_chain(chain_id).await;
+    block_cursor_repository
+        .set_paused(chain_id, contract, paused)
+        .await?;
+    tracing::info!(
+        "{} {} on chain {}",
+        if paused { "Paused" } else { "Resumed" },
+        contract,
+        chain_id
+    );
+    Ok(())
+}
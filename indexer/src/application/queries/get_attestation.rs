@@ -0,0 +1,15 @@
+use std::{error::Error, sync::Arc};
+
+use alloy::primitives::B256;
+
+use crate::domain::{
+    entities::attestation::Attestation, repositories::attestation_repository::AttestationRepository,
+};
+
+pub async fn get_attestation(
+    attestation_repository: Arc<dyn AttestationRepository>,
+    chain_id: Option<u64>,
+    uid: B256,
+) -> Result<Option<Attestation>, Box<dyn Error>> {
+    attestation_repository.find_by_uid(chain_id, &uid).await
+}
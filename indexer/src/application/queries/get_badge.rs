@@ -0,0 +1,11 @@
+use std::{error::Error, sync::Arc};
+
+use crate::domain::{entities::badge::Badge, repositories::badge_repository::BadgeRepository};
+
+pub async fn get_badge(
+    badge_repository: Arc<dyn BadgeRepository>,
+    chain_id: Option<u64>,
+    name: String,
+) -> Result<Option<Badge>, Box<dyn Error>> {
+    badge_repository.find_by_name(chain_id, &name).await
+}
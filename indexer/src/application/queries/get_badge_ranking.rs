@@ -0,0 +1,13 @@
+use std::{error::Error, sync::Arc};
+
+use crate::domain::{
+    entities::badge_upvote::{BadgeRanking, BadgeRankingQuery},
+    repositories::badge_upvote_repository::BadgeUpvoteRepository,
+};
+
+pub async fn get_badge_ranking(
+    badge_upvote_repository: Arc<dyn BadgeUpvoteRepository>,
+    query: BadgeRankingQuery,
+) -> Result<Vec<BadgeRanking>, Box<dyn Error>> {
+    badge_upvote_repository.ranking(&query).await
+}
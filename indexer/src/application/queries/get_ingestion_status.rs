@@ -0,0 +1,43 @@
+use std::{error::Error, sync::Arc};
+
+use crate::domain::{
+    entities::ingestion_status::{ChainIngestionStatus, ContractIngestionStatus},
+    repositories::block_cursor_repository::BlockCursorRepository,
+    services::{
+        ethereum_event_polling_service::EthereumEventPollingService,
+        ingestion_control::IngestionControl,
+    },
+};
+
+/// Cursor, pause state and recent activity of every contract indexed on each chain.
+pub async fn get_ingestion_status(
+    ethereum_event_polling_services: Vec<Arc<dyn EthereumEventPollingService>>,
+    block_cursor_repository: Arc<dyn BlockCursorRepository>,
+    ingestion_control: Arc<dyn IngestionControl>,
+) -> Result<Vec<ChainIngestionStatus>, Box<dyn Error>> {
+    let mut chains = Vec::new();
+    for ethereum_event_polling_service in ethereum_event_polling_services {
+        let chain_id = ethereum_event_polling_service.chain_id();
+        let cursors = block_cursor_repository.list_by_chain(chain_id).await?;
+        let paused = block_cursor_repository.list_paused(chain_id).await?;
+        let contracts = ethereum_event_polling_service
+            .contracts()
+            .into_iter()
+            .map(|contract| ContractIngestionStatus {
+                paused: paused.contains(&contract.name),
+                last_indexed_block: cursors
+                    .iter()
+                    .find(|cursor| cursor.contract == contract.name)
+                    .map(|cursor| cursor.last_indexed_block),
+                activity: ingestion_control.activity(chain_id, &contract.name),
+                contract: contract.name,
+            })
+            .collect();
+        chains.push(ChainIngestionStatus {
+            chain_id,
+            last_error: ingestion_control.last_chain_error(chain_id),
+            contracts,
+        });
+    }
+    Ok(chains)
+}
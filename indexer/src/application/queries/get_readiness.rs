@@ -0,0 +1,54 @@
+use std::{sync::Arc, time::Duration};
+
+use futures::future::join_all;
+use tokio::time::timeout;
+
+use crate::domain::{
+    entities::readiness::ChainReadiness,
+    repositories::block_cursor_repository::BlockCursorRepository,
+    services::ethereum_event_polling_service::EthereumEventPollingService,
+};
+
+/// Checks every chain concurrently, giving its RPC endpoints and the database `check_timeout`
+/// each to answer.
+pub async fn get_readiness(
+    ethereum_event_polling_services: Vec<Arc<dyn EthereumEventPollingService>>,
+    block_cursor_repository: Arc<dyn BlockCursorRepository>,
+    check_timeout: Duration,
+) -> Vec<ChainReadiness> {
+    join_all(
+        ethereum_event_polling_services
+            .into_iter()
+            .map(|ethereum_event_polling_service| {
+                let block_cursor_repository = block_cursor_repository.clone();
+                async move {
+                    let chain_id = ethereum_event_polling_service.chain_id();
+                    let head_block = match timeout(
+                        check_timeout,
+                        ethereum_event_polling_service.latest_block_number(),
+                    )
+                    .await
+                    {
+                        Ok(head_block) => head_block.map_err(|e| e.to_string()),
+                        Err(_) => Err("RPC endpoints did not answer in time".to_string()),
+                    };
+                    let cursors = match timeout(
+                        check_timeout,
+                        block_cursor_repository.list_by_chain(chain_id),
+                    )
+                    .await
+                    {
+                        Ok(cursors) => cursors.map(|_| ()).map_err(|e| e.to_string()),
+                        Err(_) => Err("Database did not answer in time".to_string()),
+                    };
+                    ChainReadiness {
+                        chain_id,
+                        head_block: head_block.as_ref().ok().copied(),
+                        rpc_error: head_block.err(),
+                        database_error: cursors.err(),
+                    }
+                }
+            }),
+    )
+    .await
+}
@@ -0,0 +1,13 @@
+use std::{error::Error, sync::Arc};
+
+use crate::domain::{
+    entities::token_balance::{TokenBalance, TopHoldersQuery},
+    repositories::token_balance_repository::TokenBalanceRepository,
+};
+
+pub async fn get_top_token_holders(
+    token_balance_repository: Arc<dyn TokenBalanceRepository>,
+    query: TopHoldersQuery,
+) -> Result<Vec<TokenBalance>, Box<dyn Error>> {
+    token_balance_repository.top_holders(&query).await
+}
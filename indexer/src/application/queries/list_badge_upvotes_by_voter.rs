@@ -0,0 +1,18 @@
+use std::{error::Error, sync::Arc};
+
+use alloy::primitives::Address;
+
+use crate::domain::{
+    entities::badge_upvote::BadgeUpvote,
+    repositories::badge_upvote_repository::BadgeUpvoteRepository,
+};
+
+pub async fn list_badge_upvotes_by_voter(
+    badge_upvote_repository: Arc<dyn BadgeUpvoteRepository>,
+    chain_id: Option<u64>,
+    voter: Address,
+) -> Result<Vec<BadgeUpvote>, Box<dyn Error>> {
+    badge_upvote_repository
+        .list_by_voter(chain_id, &voter)
+        .await
+}
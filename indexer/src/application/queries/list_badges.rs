@@ -0,0 +1,10 @@
+use std::{error::Error, sync::Arc};
+
+use crate::domain::{entities::badge::Badge, repositories::badge_repository::BadgeRepository};
+
+pub async fn list_badges(
+    badge_repository: Arc<dyn BadgeRepository>,
+    chain_id: Option<u64>,
+) -> Result<Vec<Badge>, Box<dyn Error>> {
+    badge_repository.list(chain_id).await
+}
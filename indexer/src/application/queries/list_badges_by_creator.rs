@@ -0,0 +1,13 @@
+use std::{error::Error, sync::Arc};
+
+use alloy::primitives::Address;
+
+use crate::domain::{entities::badge::Badge, repositories::badge_repository::BadgeRepository};
+
+pub async fn list_badges_by_creator(
+    badge_repository: Arc<dyn BadgeRepository>,
+    chain_id: Option<u64>,
+    creator: Address,
+) -> Result<Vec<Badge>, Box<dyn Error>> {
+    badge_repository.list_by_creator(chain_id, &creator).await
+}
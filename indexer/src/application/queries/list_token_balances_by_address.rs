@@ -0,0 +1,18 @@
+use std::{error::Error, sync::Arc};
+
+use alloy::primitives::Address;
+
+use crate::domain::{
+    entities::token_balance::TokenBalance,
+    repositories::token_balance_repository::TokenBalanceRepository,
+};
+
+pub async fn list_token_balances_by_address(
+    token_balance_repository: Arc<dyn TokenBalanceRepository>,
+    chain_id: Option<u64>,
+    address: Address,
+) -> Result<Vec<TokenBalance>, Box<dyn Error>> {
+    token_balance_repository
+        .list_by_address(chain_id, &address)
+        .await
+}
@@ -1 +1,13 @@
+pub mod get_attestation;
+pub mod get_badge;
+pub mod get_badge_ranking;
+pub mod get_ingestion_status;
+pub mod get_readiness;
+pub mod get_top_token_holders;
+pub mod list_attestations;
+pub mod list_badge_upvotes_by_voter;
+pub mod list_badges;
+pub mod list_badges_by_creator;
 pub mod list_events;
+pub mod list_token_balances_by_address;
+pub mod stream_events;
@@ -0,0 +1,245 @@
+use std::{collections::VecDeque, error::Error, sync::Arc};
+
+use futures::{Stream, stream};
+use tokio::sync::broadcast::{
+    self,
+    error::{RecvError, TryRecvError},
+};
+
+use crate::domain::{
+    entities::{ethereum_event::EthereumEvent, ethereum_event_query::EventStreamFilter},
+    repositories::ethereum_event_repository::EthereumEventRepository,
+    services::ethereum_event_broadcaster::EthereumEventBroadcaster,
+};
+
+const PAGE_SIZE: u64 = 1000;
+
+struct StreamState {
+    ethereum_event_repository: Arc<dyn EthereumEventRepository>,
+    receiver: broadcast::Receiver<EthereumEvent>,
+    filter: EventStreamFilter,
+    /// Sequence number of the last event read from the store.
+    sequence: u64,
+    page: VecDeque<EthereumEvent>,
+}
+
+/// Streams the events matching `filter` in the order they are committed.
+///
+/// When resuming from `last_sequence`, the stored events committed after it are replayed first,
+/// a page at a time. Events are always read from the store, the broadcaster only tells when new
+/// ones were committed, so a subscriber falling behind reads them later rather than missing
+/// them. The stream ends when the store cannot be read, clients then reconnect and resume.
+pub async fn stream_events(
+    ethereum_event_repository: Arc<dyn EthereumEventRepository>,
+    ethereum_event_broadcaster: Arc<dyn EthereumEventBroadcaster>,
+    filter: EventStreamFilter,
+    last_sequence: Option<u64>,
+) -> Result<impl Stream<Item = EthereumEvent> + Send + 'static, Box<dyn Error>> {
+    // Subscribing before reading the store means no commit goes unnoticed in between.
+    let receiver = ethereum_event_broadcaster.subscribe();
+    let sequence = match last_sequence {
+        Some(sequence) => sequence,
+        None => ethereum_event_repository.last_sequence().await?,
+    };
+
+    let state = StreamState {
+        ethereum_event_repository,
+        receiver,
+        filter,
+        sequence,
+        page: VecDeque::new(),
+    };
+    Ok(stream::unfold(state, |mut state| async move {
+        loop {
+            if let Some(event) = state.page.pop_front() {
+                return Some((event, state));
+            }
+
+            let page = state
+                .ethereum_event_repository
+                .list_committed_after(
+                    state.sequence,
+                    state.filter.chain_id,
+                    state.filter.event_type.as_deref(),
+                    PAGE_SIZE,
+                )
+                .await
+                .map_err(|e| e.to_string());
+            match page {
+                Ok(events) => match events.last() {
+                    Some(last) => {
+                        state.sequence = last.sequence;
+                        let filter = &state.filter;
+                        state
+                            .page
+                            .extend(events.into_iter().filter(|e| filter.matches(e)));
+                    }
+                    None => {
+                        // Caught up, so wait for the next commit
+                        match state.receiver.recv().await {
+                            Ok(_) | Err(RecvError::Lagged(_)) => {}
+                            Err(RecvError::Closed) => return None,
+                        }
+                        // Events committed together are read together
+                        while !matches!(
+                            state.receiver.try_recv(),
+                            Err(TryRecvError::Empty | TryRecvError::Closed)
+                        ) {}
+                    }
+                },
+                Err(e) => {
+                    tracing::warn!("Could not read events to stream: {e}");
+                    return None;
+                }
+            }
+        }
+    }))
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Mutex;
+
+    use async_trait::async_trait;
+    use futures::StreamExt;
+
+    use super::*;
+    use crate::{
+        domain::entities::{
+            ethereum_event::EthereumEventType,
+            ethereum_event_query::{EthereumEventPage, EthereumEventQuery},
+        },
+        infrastructure::services::tokio_ethereum_event_broadcaster::TokioEthereumEventBroadcaster,
+    };
+
+    /// Events in the order they were committed.
+    #[derive(Default)]
+    struct CommittedEvents(Mutex<Vec<EthereumEvent>>);
+
+    impl CommittedEvents {
+        fn commit(&self, chain_id: u64, block_number: u64) -> EthereumEvent {
+            let mut events = self.0.lock().unwrap();
+            let mut event = EthereumEvent::in_block(
+                chain_id,
+                block_number,
+                EthereumEventType::BadgeUpvoted {
+                    badge_name: "Rust".to_string(),
+                    voter: Default::default(),
+                },
+            );
+            event.sequence = events.len() as u64 + 1;
+            events.push(event.clone());
+            event
+        }
+    }
+
+    #[async_trait]
+    impl EthereumEventRepository for CommittedEvents {
+        async fn list(&self, _: &EthereumEventQuery) -> Result<EthereumEventPage, Box<dyn Error>> {
+            Ok(EthereumEventPage {
+                events: Vec::new(),
+                next_cursor: None,
+            })
+        }
+
+        async fn find_by_id(&self, _: &str) -> Result<Option<EthereumEvent>, Box<dyn Error>> {
+            Ok(None)
+        }
+
+        async fn list_committed_after(
+            &self,
+            sequence: u64,
+            chain_id: Option<u64>,
+            _: Option<&str>,
+            limit: u64,
+        ) -> Result<Vec<EthereumEvent>, Box<dyn Error>> {
+            let events = self.0.lock().unwrap();
+            Ok(events
+                .iter()
+                .filter(|e| e.sequence > sequence && chain_id.is_none_or(|id| e.chain_id == id))
+                .take(limit as usize)
+                .cloned()
+                .collect())
+        }
+
+        async fn last_sequence(&self) -> Result<u64, Box<dyn Error>> {
+            Ok(self.0.lock().unwrap().len() as u64)
+        }
+
+        async fn insert_many(
+            &self,
+            _: Vec<EthereumEvent>,
+        ) -> Result<Vec<EthereumEvent>, Box<dyn Error>> {
+            Ok(Vec::new())
+        }
+
+        async fn finalize_up_to(&self, _: u64, _: u64) -> Result<u64, Box<dyn Error>> {
+            Ok(0)
+        }
+
+        async fn delete_above_block(
+            &self,
+            _: u64,
+            _: u64,
+            _: Option<&str>,
+        ) -> Result<(), Box<dyn Error>> {
+            Ok(())
+        }
+    }
+
+    #[tokio::test]
+    async fn replays_page_by_page_in_commit_order_then_streams_new_commits() {
+        let events = Arc::new(CommittedEvents::default());
+        let broadcaster = Arc::new(TokioEthereumEventBroadcaster::new(1));
+        for block_number in 0..2 * PAGE_SIZE {
+            events.commit(80002, 5000 + block_number);
+        }
+        // Behind the events above on chain, but committed after them, e.g. by a contract
+        // catching up on its own cursor
+        events.commit(80002, 10);
+
+        let stream = stream_events(
+            events.clone(),
+            broadcaster.clone(),
+            EventStreamFilter::default(),
+            Some(PAGE_SIZE - 1),
+        )
+        .await
+        .unwrap();
+        let mut stream = Box::pin(stream);
+        let mut sequences = Vec::new();
+        for _ in 0..PAGE_SIZE + 2 {
+            sequences.push(stream.next().await.unwrap().sequence);
+        }
+        assert_eq!(
+            sequences,
+            (PAGE_SIZE..=2 * PAGE_SIZE + 1).collect::<Vec<_>>()
+        );
+
+        // More commits than the broadcaster holds only wake the stream up
+        let committed = [events.commit(80002, 11), events.commit(80002, 12)];
+        broadcaster.publish(&committed);
+        assert_eq!(stream.next().await.unwrap().id, committed[0].id);
+        assert_eq!(stream.next().await.unwrap().id, committed[1].id);
+    }
+
+    #[tokio::test]
+    async fn resumes_every_chain_whatever_its_height() {
+        let events = Arc::new(CommittedEvents::default());
+        let broadcaster = Arc::new(TokioEthereumEventBroadcaster::new(16));
+        let last_received = events.commit(1, 21_000_000);
+        // Committed while the client was away, on a chain far below the other one
+        let missed = [events.commit(80002, 300), events.commit(1, 21_000_001)];
+
+        let stream = stream_events(
+            events.clone(),
+            broadcaster,
+            EventStreamFilter::default(),
+            Some(last_received.sequence),
+        )
+        .await
+        .unwrap();
+        let replayed: Vec<_> = stream.take(2).map(|e| e.id).collect().await;
+        assert_eq!(replayed, [missed[0].id.clone(), missed[1].id.clone()]);
+    }
+}
@@ -0,0 +1,31 @@
+use clap::{Parser, Subcommand};
+
+#[derive(Debug, Parser)]
+#[command(
+    name = "guild-indexer",
+    about = "Indexes The Guild contracts and serves their events"
+)]
+pub struct Cli {
+    /// Runs the indexer and its API when omitted.
+    #[command(subcommand)]
+    pub command: Option<Command>,
+}
+
+#[derive(Debug, Subcommand)]
+pub enum Command {
+    /// Ingests the events of a historical block range, then exits.
+    Backfill {
+        /// First block of the range.
+        #[arg(long)]
+        from: u64,
+        /// Last block of the range, inclusive.
+        #[arg(long)]
+        to: u64,
+        /// Chain to backfill, by its chain id. Required when several chains are configured.
+        #[arg(long = "chain", value_name = "CHAIN_ID")]
+        chain_id: Option<u64>,
+        /// Contract to backfill, by its configured name. Repeat for several; defaults to all.
+        #[arg(long = "contract", value_name = "NAME")]
+        contracts: Vec<String>,
+    },
+}
@@ -0,0 +1,635 @@
+use std::{collections::BTreeMap, env, fs, time::Duration};
+
+use alloy::{
+    primitives::{Address, B256},
+    transports::http::reqwest::Url,
+};
+use anyhow::{Context, anyhow, bail};
+use serde::Deserialize;
+
+use crate::{
+    domain::entities::indexed_contract::IndexedContract,
+    infrastructure::services::{
+        resilient_transport::RpcResilienceConfig,
+        sol_event_decoders::{BUILT_IN_CONTRACTS, EAS_CONTRACT},
+    },
+};
+
+/// Name of the chain described by the top-level settings and their environment variables.
+pub const DEFAULT_CHAIN: &str = "default";
+
+/// Indexer settings, read from the optional TOML file named by `INDEXER_CONFIG` and
+/// overridden by environment variables.
+#[derive(Debug, Clone)]
+pub struct IndexerConfig {
+    /// Chains indexed side by side, each with distinct chain ids.
+    pub chains: Vec<ChainConfig>,
+    pub batch_size: u64,
+    pub poll_interval_ms: u64,
+    /// Retries, request budget and failover applied to each chain's RPC endpoints.
+    pub rpc: RpcResilienceConfig,
+    /// Bearer token guarding the admin endpoints, which are not served when unset.
+    pub admin_token: Option<String>,
+}
+
+/// A chain to index, with its own endpoints, contracts and cursors.
+#[derive(Debug, Clone)]
+pub struct ChainConfig {
+    /// Key of its `[chains.<name>]` section, or [`DEFAULT_CHAIN`] for the top-level settings.
+    pub name: String,
+    /// RPC endpoints, in order of preference.
+    pub rpc_urls: Vec<String>,
+    /// WebSocket endpoint used to subscribe to the contracts' logs; polling only when unset.
+    pub ws_url: Option<String>,
+    pub chain_id: u64,
+    /// Blocks mined on top of an event's block before it is finalized; 0 finalizes at once.
+    pub confirmation_blocks: u64,
+    /// Block to start from for contracts that have neither a cursor nor their own start block.
+    pub start_block: Option<u64>,
+    pub contracts: Vec<IndexedContract>,
+}
+
+#[derive(Debug, Default, Deserialize)]
+#[serde(deny_unknown_fields)]
+struct RawConfig {
+    rpc_urls: Option<Vec<String>>,
+    ws_url: Option<String>,
+    chain_id: Option<u64>,
+    confirmation_blocks: Option<u64>,
+    start_block: Option<u64>,
+    batch_size: Option<u64>,
+    poll_interval_ms: Option<u64>,
+    admin_token: Option<String>,
+    #[serde(default)]
+    rpc: RawRpcConfig,
+    #[serde(default)]
+    contracts: BTreeMap<String, RawContractConfig>,
+    #[serde(default)]
+    chains: BTreeMap<String, RawChainConfig>,
+}
+
+#[derive(Debug, Default, Deserialize)]
+#[serde(deny_unknown_fields)]
+struct RawRpcConfig {
+    max_retries: Option<u32>,
+    initial_backoff_ms: Option<u64>,
+    max_backoff_ms: Option<u64>,
+    requests_per_second: Option<u32>,
+    unhealthy_cooldown_ms: Option<u64>,
+}
+
+#[derive(Debug, Default, Deserialize)]
+#[serde(deny_unknown_fields)]
+struct RawChainConfig {
+    rpc_urls: Option<Vec<String>>,
+    ws_url: Option<String>,
+    chain_id: Option<u64>,
+    confirmation_blocks: Option<u64>,
+    start_block: Option<u64>,
+    #[serde(default)]
+    contracts: BTreeMap<String, RawContractConfig>,
+}
+
+impl RawChainConfig {
+    fn is_empty(&self) -> bool {
+        self.rpc_urls.is_none()
+            && self.ws_url.is_none()
+            && self.chain_id.is_none()
+            && self.confirmation_blocks.is_none()
+            && self.start_block.is_none()
+            && self.contracts.is_empty()
+    }
+}
+
+#[derive(Debug, Default, Deserialize)]
+#[serde(deny_unknown_fields)]
+struct RawContractConfig {
+    address: Option<String>,
+    start_block: Option<u64>,
+    schema_id: Option<String>,
+    abi: Option<String>,
+    events: Option<Vec<String>>,
+}
+
+impl IndexerConfig {
+    pub fn from_env() -> anyhow::Result<Self> {
+        let file = match env::var("INDEXER_CONFIG") {
+            Ok(path) => Some(
+                fs::read_to_string(&path)
+                    .with_context(|| format!("Could not read config file {path}"))?,
+            ),
+            Err(_) => None,
+        };
+        Self::from_sources(file.as_deref(), |key| env::var(key).ok())
+    }
+
+    fn from_sources(
+        file: Option<&str>,
+        var: impl Fn(&str) -> Option<String>,
+    ) -> anyhow::Result<Self> {
+        let mut raw: RawConfig = match file {
+            Some(content) => toml::from_str(content).context("Invalid config file")?,
+            None => RawConfig::default(),
+        };
+
+        if let Some(rpc_url) = var("RPC_URL") {
+            raw.rpc_urls = Some(rpc_url.split(',').map(|u| u.trim().to_string()).collect());
+        }
+        if let Some(ws_url) = var("WS_URL") {
+            raw.ws_url = Some(ws_url);
+        }
+        if let Some(chain_id) = var("CHAIN_ID") {
+            raw.chain_id = Some(parse_var("CHAIN_ID", &chain_id)?);
+        }
+        if let Some(confirmation_blocks) = var("CONFIRMATION_BLOCKS") {
+            raw.confirmation_blocks = Some(parse_var("CONFIRMATION_BLOCKS", &confirmation_blocks)?);
+        }
+        if let Some(start_block) = var("START_BLOCK") {
+            raw.start_block = Some(parse_var("START_BLOCK", &start_block)?);
+        }
+        if let Some(batch_size) = var("BATCH_SIZE") {
+            raw.batch_size = Some(parse_var("BATCH_SIZE", &batch_size)?);
+        }
+        if let Some(poll_interval) = var("POLL_INTERVAL") {
+            raw.poll_interval_ms = Some(parse_var("POLL_INTERVAL", &poll_interval)?);
+        }
+        if let Some(admin_token) = var("ADMIN_TOKEN") {
+            raw.admin_token = Some(admin_token);
+        }
+        if let Some(max_retries) = var("RPC_MAX_RETRIES") {
+            raw.rpc.max_retries = Some(parse_var("RPC_MAX_RETRIES", &max_retries)?);
+        }
+        if let Some(initial_backoff) = var("RPC_INITIAL_BACKOFF") {
+            raw.rpc.initial_backoff_ms = Some(parse_var("RPC_INITIAL_BACKOFF", &initial_backoff)?);
+        }
+        if let Some(max_backoff) = var("RPC_MAX_BACKOFF") {
+            raw.rpc.max_backoff_ms = Some(parse_var("RPC_MAX_BACKOFF", &max_backoff)?);
+        }
+        if let Some(requests_per_second) = var("RPC_REQUESTS_PER_SECOND") {
+            raw.rpc.requests_per_second =
+                Some(parse_var("RPC_REQUESTS_PER_SECOND", &requests_per_second)?);
+        }
+        if let Some(unhealthy_cooldown) = var("RPC_UNHEALTHY_COOLDOWN") {
+            raw.rpc.unhealthy_cooldown_ms =
+                Some(parse_var("RPC_UNHEALTHY_COOLDOWN", &unhealthy_cooldown)?);
+        }
+        for name in BUILT_IN_CONTRACTS {
+            let prefix = name.to_uppercase();
+            let address = var(&format!("{prefix}_ADDRESS"));
+            let start_block = var(&format!("{prefix}_START_BLOCK"));
+            let schema_id = var(&format!("{prefix}_SCHEMA_ID"));
+            if address.is_none() && start_block.is_none() && schema_id.is_none() {
+                continue;
+            }
+            let contract = raw.contracts.entry(name.to_string()).or_default();
+            if address.is_some() {
+                contract.address = address;
+            }
+            if let Some(start_block) = start_block {
+                contract.start_block =
+                    Some(parse_var(&format!("{prefix}_START_BLOCK"), &start_block)?);
+            }
+            if schema_id.is_some() {
+                contract.schema_id = schema_id;
+            }
+        }
+
+        Self::validate(raw)
+    }
+
+    /// Returns the configured chain with the given id.
+    pub fn chain(&self, chain_id: u64) -> Option<&ChainConfig> {
+        self.chains.iter().find(|chain| chain.chain_id == chain_id)
+    }
+
+    fn validate(raw: RawConfig) -> anyhow::Result<Self> {
+        let batch_size = raw.batch_size.unwrap_or(1000);
+        if batch_size == 0 {
+            bail!("BATCH_SIZE must be greater than 0");
+        }
+        let poll_interval_ms = raw.poll_interval_ms.unwrap_or(12000);
+        if poll_interval_ms == 0 {
+            bail!("POLL_INTERVAL must be greater than 0");
+        }
+        if raw.admin_token.as_deref().is_some_and(str::is_empty) {
+            bail!("ADMIN_TOKEN must not be empty");
+        }
+        let rpc = Self::validate_rpc(raw.rpc)?;
+
+        // The top-level settings may be left out once every chain has its own section.
+        let default_chain = RawChainConfig {
+            rpc_urls: raw.rpc_urls,
+            ws_url: raw.ws_url,
+            chain_id: raw.chain_id,
+            confirmation_blocks: raw.confirmation_blocks,
+            start_block: raw.start_block,
+            contracts: raw.contracts,
+        };
+        let mut chains = Vec::new();
+        if raw.chains.is_empty() || !default_chain.is_empty() {
+            chains.push(ChainConfig::validate(
+                DEFAULT_CHAIN.to_string(),
+                default_chain,
+                Some(1),
+            )?);
+        }
+        for (name, chain) in raw.chains {
+            if name == DEFAULT_CHAIN {
+                bail!("Chain name {DEFAULT_CHAIN} is reserved for the top-level settings");
+            }
+            let chain = ChainConfig::validate(name.clone(), chain, None)
+                .with_context(|| format!("Invalid chain {name}"))?;
+            if chains
+                .iter()
+                .any(|c: &ChainConfig| c.chain_id == chain.chain_id)
+            {
+                bail!("Chain id {} is configured more than once", chain.chain_id);
+            }
+            chains.push(chain);
+        }
+
+        Ok(Self {
+            chains,
+            batch_size,
+            poll_interval_ms,
+            rpc,
+            admin_token: raw.admin_token,
+        })
+    }
+
+    fn validate_rpc(raw: RawRpcConfig) -> anyhow::Result<RpcResilienceConfig> {
+        let defaults = RpcResilienceConfig::default();
+        let rpc = RpcResilienceConfig {
+            max_retries: raw.max_retries.unwrap_or(defaults.max_retries),
+            initial_backoff: raw
+                .initial_backoff_ms
+                .map_or(defaults.initial_backoff, Duration::from_millis),
+            max_backoff: raw
+                .max_backoff_ms
+                .map_or(defaults.max_backoff, Duration::from_millis),
+            requests_per_second: raw.requests_per_second,
+            unhealthy_cooldown: raw
+                .unhealthy_cooldown_ms
+                .map_or(defaults.unhealthy_cooldown, Duration::from_millis),
+        };
+        if rpc.requests_per_second == Some(0) {
+            bail!("RPC_REQUESTS_PER_SECOND must be greater than 0");
+        }
+        if rpc.max_backoff < rpc.initial_backoff {
+            bail!("RPC_MAX_BACKOFF must not be less than RPC_INITIAL_BACKOFF");
+        }
+        Ok(rpc)
+    }
+}
+
+impl ChainConfig {
+    fn validate(
+        name: String,
+        raw: RawChainConfig,
+        default_chain_id: Option<u64>,
+    ) -> anyhow::Result<Self> {
+        let rpc_urls = raw.rpc_urls.unwrap_or_default();
+        if rpc_urls.is_empty() {
+            bail!("At least one RPC URL must be set (RPC_URL)");
+        }
+        for rpc_url in &rpc_urls {
+            rpc_url
+                .parse::<Url>()
+                .with_context(|| format!("Invalid RPC URL: {rpc_url}"))?;
+        }
+
+        if let Some(ws_url) = &raw.ws_url {
+            let url = ws_url
+                .parse::<Url>()
+                .with_context(|| format!("Invalid WebSocket URL: {ws_url}"))?;
+            if !matches!(url.scheme(), "ws" | "wss") {
+                bail!("WS_URL must be a ws:// or wss:// URL, got {ws_url}");
+            }
+        }
+
+        let chain_id = raw
+            .chain_id
+            .or(default_chain_id)
+            .ok_or_else(|| anyhow!("Missing chain id"))?;
+        if chain_id == 0 {
+            bail!("CHAIN_ID must not be 0");
+        }
+
+        let mut contracts = Vec::new();
+        for (name, contract) in raw.contracts {
+            // Contracts other than the built-in ones are decoded from their ABI.
+            if BUILT_IN_CONTRACTS.contains(&name.as_str()) {
+                if contract.abi.is_some() || contract.events.is_some() {
+                    bail!("Contract {name} has a built-in decoder and takes no ABI or events");
+                }
+            } else if contract.abi.is_none() {
+                bail!(
+                    "Missing ABI for contract {name}, only {} are decoded without one",
+                    BUILT_IN_CONTRACTS.join(", ")
+                );
+            }
+            let address = contract
+                .address
+                .ok_or_else(|| anyhow!("Missing address for contract {name}"))?;
+            let address: Address = address
+                .parse()
+                .with_context(|| format!("Invalid address for contract {name}: {address}"))?;
+            if address == Address::ZERO {
+                bail!("Address for contract {name} must not be the zero address");
+            }
+            let schema_id: Option<B256> = match (name.as_str(), contract.schema_id) {
+                (EAS_CONTRACT, Some(schema_id)) => Some(
+                    schema_id
+                        .parse()
+                        .with_context(|| format!("Invalid schema id: {schema_id}"))?,
+                ),
+                (EAS_CONTRACT, None) => bail!("Missing schema id for contract {name}"),
+                (_, Some(_)) => bail!("A schema id only applies to the {EAS_CONTRACT} contract"),
+                (_, None) => None,
+            };
+            contracts.push(IndexedContract {
+                name,
+                address,
+                start_block: contract.start_block.or(raw.start_block),
+                schema_id,
+                abi: contract.abi,
+                events: contract.events.unwrap_or_default(),
+            });
+        }
+        if contracts.is_empty() {
+            bail!(
+                "At least one contract address must be set (e.g. {}_ADDRESS)",
+                BUILT_IN_CONTRACTS[0].to_uppercase()
+            );
+        }
+
+        Ok(Self {
+            name,
+            rpc_urls,
+            ws_url: raw.ws_url,
+            chain_id,
+            confirmation_blocks: raw.confirmation_blocks.unwrap_or(0),
+            start_block: raw.start_block,
+            contracts,
+        })
+    }
+}
+
+fn parse_var<T: std::str::FromStr>(key: &str, value: &str) -> anyhow::Result<T> {
+    value
+        .trim()
+        .parse()
+        .map_err(|_| anyhow!("{key} must be a valid number, got {value}"))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const ACTIVITY_TOKEN: &str = "0x5db978bc69e54250f577ed343273508baea136cd";
+
+    fn vars(pairs: &[(&str, &str)]) -> impl Fn(&str) -> Option<String> {
+        let pairs: Vec<(String, String)> = pairs
+            .iter()
+            .map(|(k, v)| (k.to_string(), v.to_string()))
+            .collect();
+        move |key| pairs.iter().find(|(k, _)| k == key).map(|(_, v)| v.clone())
+    }
+
+    #[test]
+    fn reads_file_and_lets_env_override_it() {
+        let file = format!(
+            r#"
+            rpc_urls = ["https://rpc-amoy.polygon.technology"]
+            chain_id = 80002
+            start_block = 100
+
+            [contracts.activity_token]
+            address = "{ACTIVITY_TOKEN}"
+            "#
+        );
+        let config = IndexerConfig::from_sources(
+            Some(&file),
+            vars(&[
+                ("RPC_URL", "http://localhost:8545, http://localhost:8546"),
+                ("ACTIVITY_TOKEN_START_BLOCK", "42"),
+                ("ADMIN_TOKEN", "s3cret"),
+            ]),
+        )
+        .unwrap();
+
+        assert_eq!(
+            config.chains[0].rpc_urls,
+            vec!["http://localhost:8545", "http://localhost:8546"]
+        );
+        assert_eq!(config.chains[0].chain_id, 80002);
+        assert_eq!(config.chains[0].confirmation_blocks, 0);
+        assert_eq!(config.batch_size, 1000);
+        assert_eq!(config.chains[0].contracts.len(), 1);
+        assert_eq!(config.chains[0].contracts[0].name, "activity_token");
+        assert_eq!(
+            config.chains[0].contracts[0].address,
+            ACTIVITY_TOKEN.parse::<Address>().unwrap()
+        );
+        assert_eq!(config.chains[0].contracts[0].start_block, Some(42));
+        assert_eq!(config.admin_token.as_deref(), Some("s3cret"));
+    }
+
+    #[test]
+    fn rejects_missing_rpc_url_and_contracts() {
+        let err =
+            IndexerConfig::from_sources(None, vars(&[("ACTIVITY_TOKEN_ADDRESS", ACTIVITY_TOKEN)]))
+                .unwrap_err();
+        assert!(err.to_string().contains("RPC URL"));
+
+        let err = IndexerConfig::from_sources(None, vars(&[("RPC_URL", "http://localhost:8545")]))
+            .unwrap_err();
+        assert!(err.to_string().contains("contract address"));
+    }
+
+    #[test]
+    fn requires_an_abi_for_other_contracts_and_rejects_invalid_addresses() {
+        let file = r#"
+            rpc_urls = ["http://localhost:8545"]
+
+            [contracts.unknown]
+            address = "0x5db978bc69e54250f577ed343273508baea136cd"
+        "#;
+        let err = IndexerConfig::from_sources(Some(file), vars(&[])).unwrap_err();
+        assert!(err.to_string().contains("Missing ABI for contract unknown"));
+
+        let file = r#"
+            rpc_urls = ["http://localhost:8545"]
+
+            [contracts.members]
+            address = "0x5db978bc69e54250f577ed343273508baea136cd"
+            abi = "contracts/members_abi.json"
+            events = ["MemberAdded"]
+        "#;
+        let config = IndexerConfig::from_sources(Some(file), vars(&[])).unwrap();
+        let contract = &config.chains[0].contracts[0];
+        assert_eq!(contract.abi.as_deref(), Some("contracts/members_abi.json"));
+        assert_eq!(contract.events, ["MemberAdded"]);
+
+        let file = r#"
+            rpc_urls = ["http://localhost:8545"]
+
+            [contracts.activity_token]
+            address = "0x5db978bc69e54250f577ed343273508baea136cd"
+            abi = "contracts/activity_token_abi.json"
+        "#;
+        let err = IndexerConfig::from_sources(Some(file), vars(&[])).unwrap_err();
+        assert!(err.to_string().contains("built-in decoder"));
+
+        let err = IndexerConfig::from_sources(
+            None,
+            vars(&[
+                ("RPC_URL", "http://localhost:8545"),
+                ("ACTIVITY_TOKEN_ADDRESS", "0x1234"),
+            ]),
+        )
+        .unwrap_err();
+        assert!(err.to_string().contains("Invalid address"));
+    }
+
+    #[test]
+    fn requires_a_schema_id_for_eas() {
+        let env = [
+            ("RPC_URL", "http://localhost:8545"),
+            ("EAS_ADDRESS", "0xb101275a60d8bfb14529C421899aD7CA1Ae5B5Fc"),
+        ];
+        let err = IndexerConfig::from_sources(None, vars(&env)).unwrap_err();
+        assert!(err.to_string().contains("Missing schema id"));
+
+        let schema_id = "0xb167f07504166f717f2a2710dbcfbfdf8fad6e8c6128c1a7fa80768f61b1d0b2";
+        let config = IndexerConfig::from_sources(
+            None,
+            vars(&[env[0], env[1], ("EAS_SCHEMA_ID", schema_id)]),
+        )
+        .unwrap();
+        assert_eq!(
+            config.chains[0].contracts[0].schema_id,
+            Some(schema_id.parse().unwrap())
+        );
+    }
+
+    #[test]
+    fn accepts_only_websocket_urls_for_subscriptions() {
+        let env = [
+            ("RPC_URL", "http://localhost:8545"),
+            ("ACTIVITY_TOKEN_ADDRESS", ACTIVITY_TOKEN),
+        ];
+        let config = IndexerConfig::from_sources(
+            None,
+            vars(&[env[0], env[1], ("WS_URL", "wss://localhost:8546")]),
+        )
+        .unwrap();
+        assert_eq!(
+            config.chains[0].ws_url.as_deref(),
+            Some("wss://localhost:8546")
+        );
+
+        let err = IndexerConfig::from_sources(
+            None,
+            vars(&[env[0], env[1], ("WS_URL", "http://localhost:8546")]),
+        )
+        .unwrap_err();
+        assert!(err.to_string().contains("ws://"));
+    }
+
+    #[test]
+    fn reads_rpc_resilience_settings() {
+        let env = [
+            ("RPC_URL", "http://localhost:8545"),
+            ("ACTIVITY_TOKEN_ADDRESS", ACTIVITY_TOKEN),
+        ];
+        let config = IndexerConfig::from_sources(None, vars(&env)).unwrap();
+        assert_eq!(config.rpc, RpcResilienceConfig::default());
+
+        let file = r#"
+            [rpc]
+            max_retries = 2
+            initial_backoff_ms = 100
+            requests_per_second = 10
+        "#;
+        let config = IndexerConfig::from_sources(
+            Some(file),
+            vars(&[env[0], env[1], ("RPC_REQUESTS_PER_SECOND", "25")]),
+        )
+        .unwrap();
+        assert_eq!(config.rpc.max_retries, 2);
+        assert_eq!(config.rpc.initial_backoff, Duration::from_millis(100));
+        assert_eq!(config.rpc.requests_per_second, Some(25));
+
+        let err = IndexerConfig::from_sources(
+            None,
+            vars(&[env[0], env[1], ("RPC_REQUESTS_PER_SECOND", "0")]),
+        )
+        .unwrap_err();
+        assert!(err.to_string().contains("greater than 0"));
+    }
+
+    #[test]
+    fn reads_one_section_per_chain() {
+        let file = format!(
+            r#"
+            [chains.amoy]
+            rpc_urls = ["https://rpc-amoy.polygon.technology"]
+            chain_id = 80002
+
+            [chains.amoy.contracts.activity_token]
+            address = "{ACTIVITY_TOKEN}"
+
+            [chains.anvil]
+            rpc_urls = ["http://localhost:8545"]
+            chain_id = 31337
+            confirmation_blocks = 2
+
+            [chains.anvil.contracts.activity_token]
+            address = "{ACTIVITY_TOKEN}"
+            start_block = 0
+            "#
+        );
+        let config = IndexerConfig::from_sources(Some(&file), vars(&[])).unwrap();
+
+        assert_eq!(config.chains.len(), 2);
+        assert_eq!(config.chains[0].name, "amoy");
+        assert_eq!(config.chain(31337).unwrap().name, "anvil");
+        assert_eq!(config.chain(31337).unwrap().confirmation_blocks, 2);
+        assert_eq!(
+            config.chain(31337).unwrap().contracts[0].start_block,
+            Some(0)
+        );
+
+        // Environment variables describe one more chain next to the sections.
+        let config = IndexerConfig::from_sources(
+            Some(&file),
+            vars(&[
+                ("RPC_URL", "https://sepolia.base.org"),
+                ("CHAIN_ID", "84532"),
+                ("ACTIVITY_TOKEN_ADDRESS", ACTIVITY_TOKEN),
+            ]),
+        )
+        .unwrap();
+        assert_eq!(config.chains.len(), 3);
+        assert_eq!(config.chain(84532).unwrap().name, DEFAULT_CHAIN);
+
+        let err = IndexerConfig::from_sources(
+            Some(&file),
+            vars(&[
+                ("RPC_URL", "http://localhost:8546"),
+                ("CHAIN_ID", "31337"),
+                ("ACTIVITY_TOKEN_ADDRESS", ACTIVITY_TOKEN),
+            ]),
+        )
+        .unwrap_err();
+        assert!(err.to_string().contains("configured more than once"));
+
+        let err = IndexerConfig::from_sources(
+            Some("[chains.amoy]\nrpc_urls = [\"http://localhost:8545\"]"),
+            vars(&[]),
+        )
+        .unwrap_err();
+        assert!(format!("{err:#}").contains("Missing chain id"));
+    }
+}
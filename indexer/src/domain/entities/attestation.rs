@@ -0,0 +1,57 @@
+use alloy::primitives::{Address, B256};
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+
+use crate::domain::entities::ethereum_event::{EthereumEvent, EthereumEventType};
+
+/// An EAS attestation made against our badge schema, projected from its `Attested` event.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Attestation {
+    pub chain_id: u64,
+    pub uid: B256,
+    pub attester: Address,
+    pub recipient: Address,
+    pub badge_name: String,
+    pub justification: String,
+    pub time: DateTime<Utc>,
+    pub revoked: bool,
+    pub revocation_time: Option<DateTime<Utc>>,
+    pub block_number: u64,
+}
+
+impl Attestation {
+    pub fn from_event(event: &EthereumEvent) -> Option<Self> {
+        match &event.event_type {
+            EthereumEventType::AttestationCreated {
+                uid,
+                attester,
+                recipient,
+                badge_name,
+                justification,
+                time,
+            } => Some(Self {
+                chain_id: event.chain_id,
+                uid: *uid,
+                attester: *attester,
+                recipient: *recipient,
+                badge_name: badge_name.clone(),
+                justification: justification.clone(),
+                time: DateTime::from_timestamp(*time as i64, 0).unwrap_or_default(),
+                revoked: false,
+                revocation_time: None,
+                block_number: event.block_number,
+            }),
+            _ => None,
+        }
+    }
+}
+
+/// Optional criteria narrowing down a list of attestations.
+#[derive(Debug, Clone, Default)]
+pub struct AttestationFilter {
+    pub chain_id: Option<u64>,
+    pub attester: Option<Address>,
+    pub recipient: Option<Address>,
+    pub badge_name: Option<String>,
+    pub include_revoked: bool,
+}
@@ -0,0 +1,36 @@
+use alloy::primitives::Address;
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+
+use crate::domain::entities::ethereum_event::{EthereumEvent, EthereumEventType};
+
+/// A badge registered in TheGuildBadgeRegistry, projected from its `BadgeCreated` event.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Badge {
+    pub chain_id: u64,
+    pub name: String,
+    pub description: String,
+    pub creator: Address,
+    pub block_number: u64,
+    pub timestamp: DateTime<Utc>,
+}
+
+impl Badge {
+    pub fn from_event(event: &EthereumEvent) -> Option<Self> {
+        match &event.event_type {
+            EthereumEventType::BadgeCreated {
+                name,
+                description,
+                creator,
+            } => Some(Self {
+                chain_id: event.chain_id,
+                name: name.clone(),
+                description: description.clone(),
+                creator: *creator,
+                block_number: event.block_number,
+                timestamp: event.timestamp,
+            }),
+            _ => None,
+        }
+    }
+}
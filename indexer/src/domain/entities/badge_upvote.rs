@@ -0,0 +1,50 @@
+use alloy::primitives::Address;
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+
+use crate::domain::entities::{
+    ethereum_event::{EthereumEvent, EthereumEventType},
+    sort_order::SortOrder,
+};
+
+/// A vote cast in TheGuildBadgeRanking, projected from its `BadgeUpvoted` event.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct BadgeUpvote {
+    pub chain_id: u64,
+    pub badge_name: String,
+    pub voter: Address,
+    pub block_number: u64,
+    pub timestamp: DateTime<Utc>,
+}
+
+impl BadgeUpvote {
+    pub fn from_event(event: &EthereumEvent) -> Option<Self> {
+        match &event.event_type {
+            EthereumEventType::BadgeUpvoted { badge_name, voter } => Some(Self {
+                chain_id: event.chain_id,
+                badge_name: badge_name.clone(),
+                voter: *voter,
+                block_number: event.block_number,
+                timestamp: event.timestamp,
+            }),
+            _ => None,
+        }
+    }
+}
+
+/// A badge and its materialized number of upvotes.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct BadgeRanking {
+    pub chain_id: u64,
+    pub badge_name: String,
+    pub upvotes: u64,
+}
+
+/// Page of the badge ranking, ordered by upvotes then badge name, optionally restricted to a chain.
+#[derive(Debug, Clone)]
+pub struct BadgeRankingQuery {
+    pub chain_id: Option<u64>,
+    pub order: SortOrder,
+    pub limit: u64,
+    pub offset: u64,
+}
@@ -0,0 +1,27 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+
+/// Checkpoint of the last block whose logs have been fully ingested for a contract on a chain.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct BlockCursor {
+    pub chain_id: u64,
+    pub contract: String,
+    pub last_indexed_block: u64,
+    pub updated_at: DateTime<Utc>,
+}
+
+impl BlockCursor {
+    pub fn new(chain_id: u64, contract: String, last_indexed_block: u64) -> Self {
+        Self {
+            chain_id,
+            contract,
+            last_indexed_block,
+            updated_at: Utc::now(),
+        }
+    }
+
+    /// First block that still has to be ingested.
+    pub fn next_block(&self) -> u64 {
+        self.last_indexed_block + 1
+    }
+}
@@ -0,0 +1,9 @@
+use serde::{Deserialize, Serialize};
+
+/// Hash linkage of a block the indexer has ingested, used to detect chain reorganizations.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct BlockHeader {
+    pub number: u64,
+    pub hash: String,
+    pub parent_hash: String,
+}
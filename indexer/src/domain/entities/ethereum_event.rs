@@ -1,47 +1,380 @@
-use alloy::primitives::{Address, U256};
+use std::{fmt, str::FromStr};
+
+use alloy::primitives::{Address, B256, U256};
 use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
+use serde_json::{Map, Value, json};
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+/// Stored as its variant name plus a JSON payload of the fields, see [`EthereumEventType::to_parts`].
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(tag = "type", content = "payload")]
 pub enum EthereumEventType {
-    ActivityTokenMinted { recipient: Address, amount: U256 },
+    ActivityTokenMinted {
+        #[serde(with = "checksummed_address")]
+        recipient: Address,
+        #[serde(with = "u256_decimal")]
+        amount: U256,
+    },
+    /// Any transfer of the activity token other than a mint, burns included.
+    ActivityTokenTransferred {
+        #[serde(with = "checksummed_address")]
+        from: Address,
+        #[serde(with = "checksummed_address")]
+        to: Address,
+        #[serde(with = "u256_decimal")]
+        amount: U256,
+    },
+    BadgeCreated {
+        name: String,
+        description: String,
+        #[serde(with = "checksummed_address")]
+        creator: Address,
+    },
+    BadgeUpvoted {
+        badge_name: String,
+        #[serde(with = "checksummed_address")]
+        voter: Address,
+    },
+    AttestationCreated {
+        uid: B256,
+        #[serde(with = "checksummed_address")]
+        attester: Address,
+        #[serde(with = "checksummed_address")]
+        recipient: Address,
+        badge_name: String,
+        justification: String,
+        time: u64,
+    },
+    AttestationRevoked {
+        uid: B256,
+        #[serde(with = "checksummed_address")]
+        attester: Address,
+        #[serde(with = "checksummed_address")]
+        recipient: Address,
+        revocation_time: u64,
+    },
+    /// Event of a contract registered from its ABI, with its parameters decoded to JSON:
+    /// addresses checksummed, integers as decimal strings and bytes as hex.
+    Decoded {
+        /// Name the contract is configured under.
+        contract: String,
+        event: String,
+        params: Map<String, Value>,
+    },
+}
+
+impl EthereumEventType {
+    pub fn name(&self) -> &'static str {
+        match self {
+            Self::ActivityTokenMinted { .. } => "ActivityTokenMinted",
+            Self::ActivityTokenTransferred { .. } => "ActivityTokenTransferred",
+            Self::BadgeCreated { .. } => "BadgeCreated",
+            Self::BadgeUpvoted { .. } => "BadgeUpvoted",
+            Self::AttestationCreated { .. } => "AttestationCreated",
+            Self::AttestationRevoked { .. } => "AttestationRevoked",
+            Self::Decoded { .. } => "Decoded",
+        }
+    }
+
+    /// Every account the event refers to, whatever its role.
+    pub fn addresses(&self) -> Vec<Address> {
+        match self {
+            Self::ActivityTokenMinted { recipient, .. } => vec![*recipient],
+            Self::ActivityTokenTransferred { from, to, .. } => vec![*from, *to],
+            Self::BadgeCreated { creator, .. } => vec![*creator],
+            Self::BadgeUpvoted { voter, .. } => vec![*voter],
+            Self::AttestationCreated {
+                attester,
+                recipient,
+                ..
+            }
+            | Self::AttestationRevoked {
+                attester,
+                recipient,
+                ..
+            } => vec![*attester, *recipient],
+            // Parameter types are not kept, so any value reading as an address counts.
+            Self::Decoded { params, .. } => params
+                .values()
+                .filter_map(|value| value.as_str()?.parse().ok())
+                .collect(),
+        }
+    }
+
+    /// Account receiving what the event is about: minted or transferred tokens, or an attestation.
+    pub fn recipient(&self) -> Option<Address> {
+        match self {
+            Self::ActivityTokenMinted { recipient, .. }
+            | Self::AttestationCreated { recipient, .. }
+            | Self::AttestationRevoked { recipient, .. } => Some(*recipient),
+            Self::ActivityTokenTransferred { to, .. } => Some(*to),
+            _ => None,
+        }
+    }
+
+    /// Account that created what the event is about: a badge, or an attestation.
+    pub fn creator(&self) -> Option<Address> {
+        match self {
+            Self::BadgeCreated { creator, .. } => Some(*creator),
+            Self::AttestationCreated { attester, .. }
+            | Self::AttestationRevoked { attester, .. } => Some(*attester),
+            _ => None,
+        }
+    }
+
+    /// Splits the event into its variant name and a JSON object of its fields.
+    pub fn to_parts(&self) -> Result<(String, Value), serde_json::Error> {
+        let mut value = serde_json::to_value(self)?;
+        let kind = value["type"].as_str().unwrap_or_default().to_string();
+        Ok((kind, value["payload"].take()))
+    }
+
+    pub fn from_parts(kind: &str, payload: Value) -> Result<Self, serde_json::Error> {
+        serde_json::from_value(json!({ "type": kind, "payload": payload }))
+    }
+}
+
+/// Whether an event could still be reorged away, judged by the chain's confirmation depth.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum EventStatus {
+    /// Fewer blocks than the confirmation depth were mined on top of the event's block.
+    #[default]
+    Pending,
+    Finalized,
+}
+
+impl EventStatus {
+    /// Status of an event emitted in `block_number` once the chain head is at `head_block`.
+    pub fn at(block_number: u64, head_block: u64, confirmation_blocks: u64) -> Self {
+        if block_number.saturating_add(confirmation_blocks) <= head_block {
+            Self::Finalized
+        } else {
+            Self::Pending
+        }
+    }
+
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::Pending => "pending",
+            Self::Finalized => "finalized",
+        }
+    }
+}
+
+impl fmt::Display for EventStatus {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl FromStr for EventStatus {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "pending" => Ok(Self::Pending),
+            "finalized" => Ok(Self::Finalized),
+            _ => Err(format!("Unknown event status: {s}")),
+        }
+    }
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct EthereumEvent {
     pub id: String,
     pub event_type: EthereumEventType,
+    pub chain_id: u64,
+    /// Name of the configured contract that emitted the event.
+    pub contract: String,
+    pub tx_hash: String,
+    pub log_index: u64,
+    pub block_number: u64,
+    pub block_hash: String,
+    pub status: EventStatus,
+    /// Timestamp of the block the event was emitted in.
     pub timestamp: DateTime<Utc>,
+    /// When the indexer ingested the event.
     pub created_at: DateTime<Utc>,
+    /// Position of the event in the order events were committed, assigned once it is stored.
+    pub sequence: u64,
 }
 
 impl EthereumEvent {
-    fn new(
-        id: String,
-        event_type: EthereumEventType,
+    /// Event decoded from a log, identified by its chain, transaction and position in the block.
+    #[allow(clippy::too_many_arguments)]
+    pub fn new(
+        chain_id: u64,
+        contract: String,
+        tx_hash: String,
+        log_index: u64,
+        block_number: u64,
+        block_hash: String,
         timestamp: DateTime<Utc>,
-        created_at: DateTime<Utc>,
+        event_type: EthereumEventType,
     ) -> Self {
         Self {
-            id,
+            id: format!("{chain_id}-{tx_hash}-{log_index}"),
             event_type,
+            chain_id,
+            contract,
+            tx_hash,
+            log_index,
+            block_number,
+            block_hash,
+            status: EventStatus::default(),
             timestamp,
-            created_at,
+            created_at: Utc::now(),
+            sequence: 0,
         }
     }
 
-    pub fn new_activity_token_minted_from_transfer_data(
-        tx_hash: String,
-        log_index: String,
-        recipient: Address,
-        amount: U256,
-    ) -> Self {
-        let now = Utc::now();
+    /// First event of the only transaction of the block, for tests.
+    #[cfg(test)]
+    pub fn in_block(chain_id: u64, block_number: u64, event_type: EthereumEventType) -> Self {
         Self::new(
-            format!("{tx_hash}-{log_index}"),
-            EthereumEventType::ActivityTokenMinted { recipient, amount },
-            now,
-            now,
+            chain_id,
+            "test".to_string(),
+            format!("0x{block_number:x}"),
+            0,
+            block_number,
+            format!("0x{block_number:x}"),
+            Utc::now(),
+            event_type,
         )
     }
 }
+
+/// Addresses are stored with their EIP-55 checksum rather than alloy's lowercase hex.
+mod checksummed_address {
+    use alloy::primitives::Address;
+    use serde::{Deserialize, Deserializer, Serializer};
+
+    pub fn serialize<S: Serializer>(address: &Address, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(&address.to_checksum(None))
+    }
+
+    pub fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Address, D::Error> {
+        String::deserialize(deserializer)?
+            .parse()
+            .map_err(serde::de::Error::custom)
+    }
+}
+
+/// Token amounts are stored as decimal strings so they stay readable and exact in JSONB.
+pub(crate) mod u256_decimal {
+    use alloy::primitives::U256;
+    use serde::{Deserialize, Deserializer, Serializer};
+
+    pub fn serialize<S: Serializer>(value: &U256, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(&value.to_string())
+    }
+
+    pub fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<U256, D::Error> {
+        U256::from_str_radix(&String::deserialize(deserializer)?, 10)
+            .map_err(serde::de::Error::custom)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn event_type_round_trips_through_its_parts() {
+        let event_type = EthereumEventType::ActivityTokenMinted {
+            recipient: "0xd8da6bf26964af9d7eed9e03e53415d37aa96045"
+                .parse()
+                .unwrap(),
+            amount: U256::from(10).pow(U256::from(30)),
+        };
+
+        let (kind, payload) = event_type.to_parts().unwrap();
+        assert_eq!(kind, "ActivityTokenMinted");
+        assert_eq!(
+            payload,
+            json!({
+                "recipient": "0xd8dA6BF26964aF9D7eEd9e03E53415D37aA96045",
+                "amount": "1000000000000000000000000000000",
+            })
+        );
+        assert_eq!(
+            EthereumEventType::from_parts(&kind, payload).unwrap(),
+            event_type
+        );
+    }
+
+    #[test]
+    fn decoded_event_refers_to_its_address_parameters() {
+        let event_type = EthereumEventType::Decoded {
+            contract: "registry".to_string(),
+            event: "MemberAdded".to_string(),
+            params: json!({
+                "member": "0xd8dA6BF26964aF9D7eEd9e03E53415D37aA96045",
+                "role": "0x61646d696e",
+                "weight": "3",
+            })
+            .as_object()
+            .unwrap()
+            .clone(),
+        };
+
+        assert_eq!(
+            event_type.addresses(),
+            vec![
+                "0xd8dA6BF26964aF9D7eEd9e03E53415D37aA96045"
+                    .parse::<Address>()
+                    .unwrap()
+            ]
+        );
+        let (kind, payload) = event_type.to_parts().unwrap();
+        assert_eq!(kind, "Decoded");
+        assert_eq!(
+            EthereumEventType::from_parts(&kind, payload).unwrap(),
+            event_type
+        );
+    }
+
+    #[test]
+    fn recipient_and_creator_are_read_from_the_field_of_each_event_type() {
+        let (alice, bob) = (Address::repeat_byte(1), Address::repeat_byte(2));
+        let transfer = EthereumEventType::ActivityTokenTransferred {
+            from: alice,
+            to: bob,
+            amount: U256::from(1),
+        };
+        assert_eq!(
+            (transfer.recipient(), transfer.creator()),
+            (Some(bob), None)
+        );
+
+        let attestation = EthereumEventType::AttestationRevoked {
+            uid: B256::ZERO,
+            attester: alice,
+            recipient: bob,
+            revocation_time: 0,
+        };
+        assert_eq!(
+            (attestation.recipient(), attestation.creator()),
+            (Some(bob), Some(alice))
+        );
+
+        let upvote = EthereumEventType::BadgeUpvoted {
+            badge_name: "Rust".to_string(),
+            voter: alice,
+        };
+        assert_eq!((upvote.recipient(), upvote.creator()), (None, None));
+    }
+
+    #[test]
+    fn events_are_finalized_once_buried_under_the_confirmation_depth() {
+        assert_eq!(EventStatus::at(100, 111, 12), EventStatus::Pending);
+        assert_eq!(EventStatus::at(100, 112, 12), EventStatus::Finalized);
+        assert_eq!(EventStatus::at(100, 100, 0), EventStatus::Finalized);
+    }
+
+    #[test]
+    fn unknown_event_type_is_rejected() {
+        assert!(EthereumEventType::from_parts("Unknown", json!({})).is_err());
+    }
+}
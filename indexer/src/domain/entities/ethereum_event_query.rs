@@ -0,0 +1,126 @@
+use std::{fmt, str::FromStr};
+
+use alloy::primitives::Address;
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+
+use crate::domain::entities::{
+    ethereum_event::{EthereumEvent, EventStatus},
+    sort_order::SortOrder,
+};
+
+/// Criteria and page of a listing of events, ordered by block, log index then id.
+#[derive(Debug, Clone, Default)]
+pub struct EthereumEventQuery {
+    pub chain_id: Option<u64>,
+    pub event_type: Option<String>,
+    pub recipient: Option<Address>,
+    pub creator: Option<Address>,
+    pub from_block: Option<u64>,
+    pub to_block: Option<u64>,
+    pub from_time: Option<DateTime<Utc>>,
+    pub to_time: Option<DateTime<Utc>>,
+    /// Only events with this status, any status when unset.
+    pub status: Option<EventStatus>,
+    /// Resumes the listing right after the event the cursor was taken from.
+    pub cursor: Option<EventCursor>,
+    pub order: SortOrder,
+    pub limit: u64,
+}
+
+/// Position of an event in the listing order, handed out as an opaque token. Positions of
+/// different chains do not tell which event was committed first, so streams resume from the
+/// events' sequence numbers instead.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct EventCursor {
+    pub block_number: u64,
+    pub log_index: u64,
+    pub id: String,
+}
+
+impl EventCursor {
+    pub fn from_event(event: &EthereumEvent) -> Self {
+        Self {
+            block_number: event.block_number,
+            log_index: event.log_index,
+            id: event.id.clone(),
+        }
+    }
+}
+
+impl fmt::Display for EventCursor {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}:{}:{}", self.block_number, self.log_index, self.id)
+    }
+}
+
+impl FromStr for EventCursor {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut parts = s.splitn(3, ':');
+        let (Some(block_number), Some(log_index), Some(id)) =
+            (parts.next(), parts.next(), parts.next())
+        else {
+            return Err(format!("Invalid cursor: {s}"));
+        };
+        Ok(Self {
+            block_number: block_number
+                .parse()
+                .map_err(|_| format!("Invalid cursor: {s}"))?,
+            log_index: log_index
+                .parse()
+                .map_err(|_| format!("Invalid cursor: {s}"))?,
+            id: id.to_string(),
+        })
+    }
+}
+
+/// Criteria of a live event stream, checked against each event as it is committed.
+#[derive(Debug, Clone, Default)]
+pub struct EventStreamFilter {
+    pub chain_id: Option<u64>,
+    pub event_type: Option<String>,
+    /// Matches events referring to the address in any role (recipient, creator, attester...).
+    pub address: Option<Address>,
+}
+
+impl EventStreamFilter {
+    pub fn matches(&self, event: &EthereumEvent) -> bool {
+        self.chain_id
+            .is_none_or(|chain_id| event.chain_id == chain_id)
+            && self
+                .event_type
+                .as_ref()
+                .is_none_or(|event_type| event.event_type.name() == event_type)
+            && self
+                .address
+                .is_none_or(|address| event.event_type.addresses().contains(&address))
+    }
+}
+
+/// A page of events, with the cursor of the next page when there is one.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct EthereumEventPage {
+    pub events: Vec<EthereumEvent>,
+    pub next_cursor: Option<String>,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn cursor_round_trips_through_its_token() {
+        let cursor = EventCursor {
+            block_number: 42,
+            log_index: 3,
+            id: "1-0xabc-3".to_string(),
+        };
+
+        assert_eq!(cursor.to_string(), "42:3:1-0xabc-3");
+        assert_eq!(cursor.to_string().parse::<EventCursor>(), Ok(cursor));
+        assert!("42:x:1-0xabc-3".parse::<EventCursor>().is_err());
+        assert!("42".parse::<EventCursor>().is_err());
+    }
+}
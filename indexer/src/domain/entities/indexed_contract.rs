@@ -0,0 +1,17 @@
+use alloy::primitives::{Address, B256};
+use serde::{Deserialize, Serialize};
+
+/// A deployed contract whose events the indexer ingests.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct IndexedContract {
+    pub name: String,
+    pub address: Address,
+    /// Block to start from when the contract has no cursor yet, e.g. its deployment block.
+    pub start_block: Option<u64>,
+    /// EAS schema whose attestations are indexed, only set for the EAS contract.
+    pub schema_id: Option<B256>,
+    /// Path of the JSON ABI the events are decoded with, for contracts without a built-in decoder.
+    pub abi: Option<String>,
+    /// Events of the ABI to capture, all of them when empty.
+    pub events: Vec<String>,
+}
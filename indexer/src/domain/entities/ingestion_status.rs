@@ -0,0 +1,38 @@
+use chrono::{DateTime, Utc};
+use serde::Serialize;
+
+/// Ingestion of every contract of a chain, as shown to operators.
+#[derive(Debug, Clone, Serialize)]
+pub struct ChainIngestionStatus {
+    pub chain_id: u64,
+    /// Last error of a polling pass that happened before any contract was polled, e.g. while
+    /// fetching the chain head.
+    pub last_error: Option<IngestionError>,
+    pub contracts: Vec<ContractIngestionStatus>,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct ContractIngestionStatus {
+    pub contract: String,
+    pub paused: bool,
+    /// Last block ingested, unset until the contract is first polled.
+    pub last_indexed_block: Option<u64>,
+    #[serde(flatten)]
+    pub activity: ContractActivity,
+}
+
+/// How the polling passes of this instance went for a contract lately.
+#[derive(Debug, Clone, Default, PartialEq, Serialize)]
+pub struct ContractActivity {
+    pub last_error: Option<IngestionError>,
+    /// Blocks ingested per minute, averaged over the last few minutes.
+    pub blocks_per_minute: f64,
+    /// Events stored per minute, averaged over the last few minutes.
+    pub events_per_minute: f64,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct IngestionError {
+    pub message: String,
+    pub at: DateTime<Utc>,
+}
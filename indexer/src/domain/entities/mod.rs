@@ -1 +1,12 @@
+pub mod attestation;
+pub mod badge;
+pub mod badge_upvote;
+pub mod block_cursor;
+pub mod block_header;
 pub mod ethereum_event;
+pub mod ethereum_event_query;
+pub mod indexed_contract;
+pub mod ingestion_status;
+pub mod readiness;
+pub mod sort_order;
+pub mod token_balance;
@@ -0,0 +1,17 @@
+use serde::Serialize;
+
+/// Whether a chain can be indexed right now: its RPC endpoints and the database both answer.
+#[derive(Debug, Clone, Serialize)]
+pub struct ChainReadiness {
+    pub chain_id: u64,
+    /// Chain head reported by the RPC endpoints, unset when they could not be reached.
+    pub head_block: Option<u64>,
+    pub rpc_error: Option<String>,
+    pub database_error: Option<String>,
+}
+
+impl ChainReadiness {
+    pub fn is_ready(&self) -> bool {
+        self.rpc_error.is_none() && self.database_error.is_none()
+    }
+}
@@ -0,0 +1,10 @@
+use serde::{Deserialize, Serialize};
+
+/// Direction of a listing, given as `asc` or `desc` in query strings.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum SortOrder {
+    Asc,
+    #[default]
+    Desc,
+}
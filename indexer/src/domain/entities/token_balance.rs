@@ -0,0 +1,133 @@
+use alloy::primitives::{Address, U256};
+use serde::{Deserialize, Serialize};
+
+use crate::domain::entities::ethereum_event::{EthereumEvent, EthereumEventType, u256_decimal};
+
+/// Activity token balance of an account, materialized from the token's mints and transfers.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TokenBalance {
+    pub chain_id: u64,
+    pub address: Address,
+    #[serde(with = "u256_decimal")]
+    pub balance: U256,
+    /// Whether the account was seen sending more than it was seen receiving, because it received
+    /// tokens before the blocks ingested so far. Its balance is then unknown and reported as 0.
+    pub incomplete: bool,
+}
+
+impl TokenBalance {
+    /// Balance projected from the ingested transfers, as a signed decimal sum of their amounts.
+    pub fn from_sum(chain_id: u64, address: Address, sum: &str) -> Result<Self, String> {
+        let (balance, incomplete) = match sum.strip_prefix('-') {
+            Some(_) => (U256::ZERO, true),
+            None => (
+                U256::from_str_radix(sum, 10).map_err(|e| format!("Invalid balance {sum}: {e}"))?,
+                false,
+            ),
+        };
+        Ok(Self {
+            chain_id,
+            address,
+            balance,
+            incomplete,
+        })
+    }
+}
+
+/// Effect of a mint or transfer on one account's balance.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TokenBalanceChange {
+    pub chain_id: u64,
+    pub address: Address,
+    pub amount: U256,
+    /// Whether the account received the amount rather than sent it.
+    pub received: bool,
+}
+
+impl TokenBalanceChange {
+    /// The zero address, which mints come from and burns go to, has no balance.
+    pub fn from_event(event: &EthereumEvent) -> Vec<Self> {
+        let change = |address: Address, amount: U256, received: bool| Self {
+            chain_id: event.chain_id,
+            address,
+            amount,
+            received,
+        };
+        let changes = match &event.event_type {
+            EthereumEventType::ActivityTokenMinted { recipient, amount } => {
+                vec![change(*recipient, *amount, true)]
+            }
+            EthereumEventType::ActivityTokenTransferred { from, to, amount } => {
+                vec![change(*from, *amount, false), change(*to, *amount, true)]
+            }
+            _ => Vec::new(),
+        };
+        changes
+            .into_iter()
+            .filter(|change| change.address != Address::ZERO)
+            .collect()
+    }
+
+    /// Signed decimal amount added to the balance.
+    pub fn delta(&self) -> String {
+        if self.received {
+            self.amount.to_string()
+        } else {
+            format!("-{}", self.amount)
+        }
+    }
+}
+
+/// Page of the holders of a chain's activity token, largest balance first.
+#[derive(Debug, Clone)]
+pub struct TopHoldersQuery {
+    pub chain_id: Option<u64>,
+    pub limit: u64,
+    pub offset: u64,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn transfers_debit_the_sender_and_credit_the_recipient() {
+        let alice = Address::repeat_byte(1);
+        let bob = Address::repeat_byte(2);
+        let amount = U256::from(5);
+        let transfer = |from, to| {
+            EthereumEvent::in_block(
+                80002,
+                1,
+                EthereumEventType::ActivityTokenTransferred { from, to, amount },
+            )
+        };
+
+        let changes = TokenBalanceChange::from_event(&transfer(alice, bob));
+        assert_eq!(changes.len(), 2);
+        assert_eq!(
+            (changes[0].address, changes[0].delta()),
+            (alice, "-5".into())
+        );
+        assert_eq!((changes[1].address, changes[1].delta()), (bob, "5".into()));
+
+        let burn = TokenBalanceChange::from_event(&transfer(alice, Address::ZERO));
+        assert_eq!(burn.len(), 1);
+        assert_eq!(burn[0].delta(), "-5");
+    }
+
+    #[test]
+    fn balance_of_a_sender_without_ingested_credit_is_flagged_incomplete() {
+        let sender = Address::repeat_byte(1);
+
+        // Received its tokens before the first ingested block
+        let balance = TokenBalance::from_sum(80002, sender, "-5").unwrap();
+        assert_eq!(balance.balance, U256::ZERO);
+        assert!(balance.incomplete);
+
+        let balance = TokenBalance::from_sum(80002, sender, "12").unwrap();
+        assert_eq!(balance.balance, U256::from(12));
+        assert!(!balance.incomplete);
+        assert!(TokenBalance::from_sum(80002, sender, "1.5").is_err());
+    }
+}
@@ -0,0 +1,18 @@
+use std::error::Error;
+
+use alloy::primitives::B256;
+use async_trait::async_trait;
+
+use crate::domain::entities::attestation::{Attestation, AttestationFilter};
+
+/// Read side of the attestation projection, which is written alongside the ingested events.
+#[async_trait]
+pub trait AttestationRepository: Send + Sync {
+    async fn list(&self, filter: &AttestationFilter) -> Result<Vec<Attestation>, Box<dyn Error>>;
+    /// Without a `chain_id`, the attestation of the lowest chain id is returned.
+    async fn find_by_uid(
+        &self,
+        chain_id: Option<u64>,
+        uid: &B256,
+    ) -> Result<Option<Attestation>, Box<dyn Error>>;
+}
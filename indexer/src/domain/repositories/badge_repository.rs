@@ -0,0 +1,25 @@
+use std::error::Error;
+
+use alloy::primitives::Address;
+use async_trait::async_trait;
+
+use crate::domain::entities::badge::Badge;
+
+/// Read side of the badge projection, which is written alongside the ingested events.
+/// Every lookup covers all chains unless a `chain_id` is given.
+#[async_trait]
+pub trait BadgeRepository: Send + Sync {
+    async fn list(&self, chain_id: Option<u64>) -> Result<Vec<Badge>, Box<dyn Error>>;
+    /// Badge names are only unique per chain: without a `chain_id`, the badge of the
+    /// lowest chain id is returned.
+    async fn find_by_name(
+        &self,
+        chain_id: Option<u64>,
+        name: &str,
+    ) -> Result<Option<Badge>, Box<dyn Error>>;
+    async fn list_by_creator(
+        &self,
+        chain_id: Option<u64>,
+        creator: &Address,
+    ) -> Result<Vec<Badge>, Box<dyn Error>>;
+}
@@ -0,0 +1,18 @@
+use std::error::Error;
+
+use alloy::primitives::Address;
+use async_trait::async_trait;
+
+use crate::domain::entities::badge_upvote::{BadgeRanking, BadgeRankingQuery, BadgeUpvote};
+
+/// Read side of the upvote projection and its per-badge vote counts.
+#[async_trait]
+pub trait BadgeUpvoteRepository: Send + Sync {
+    async fn ranking(&self, query: &BadgeRankingQuery)
+    -> Result<Vec<BadgeRanking>, Box<dyn Error>>;
+    async fn list_by_voter(
+        &self,
+        chain_id: Option<u64>,
+        voter: &Address,
+    ) -> Result<Vec<BadgeUpvote>, Box<dyn Error>>;
+}
@@ -0,0 +1,28 @@
+use std::error::Error;
+
+use async_trait::async_trait;
+
+use crate::domain::entities::block_cursor::BlockCursor;
+
+#[async_trait]
+pub trait BlockCursorRepository: Send + Sync {
+    async fn find_by_contract(
+        &self,
+        chain_id: u64,
+        contract: &str,
+    ) -> Result<Option<BlockCursor>, Box<dyn Error>>;
+    /// Cursors of every contract indexed on the chain, by contract name.
+    async fn list_by_chain(&self, chain_id: u64) -> Result<Vec<BlockCursor>, Box<dyn Error>>;
+    async fn save(&self, block_cursor: &BlockCursor) -> Result<(), Box<dyn Error>>;
+    /// Moves every cursor of the chain past `block_number` back to it.
+    async fn rewind(&self, chain_id: u64, block_number: u64) -> Result<(), Box<dyn Error>>;
+    /// Pauses or resumes the ingestion of a contract, whether it has a cursor yet or not.
+    async fn set_paused(
+        &self,
+        chain_id: u64,
+        contract: &str,
+        paused: bool,
+    ) -> Result<(), Box<dyn Error>>;
+    /// Names of the chain's contracts whose ingestion is paused.
+    async fn list_paused(&self, chain_id: u64) -> Result<Vec<String>, Box<dyn Error>>;
+}
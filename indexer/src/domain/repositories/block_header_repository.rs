@@ -0,0 +1,20 @@
+use std::error::Error;
+
+use async_trait::async_trait;
+
+use crate::domain::entities::block_header::BlockHeader;
+
+/// Headers are tracked per chain, each chain forking independently of the others.
+#[async_trait]
+pub trait BlockHeaderRepository: Send + Sync {
+    /// Returns the stored headers of the chain, highest block first.
+    async fn list_recent(
+        &self,
+        chain_id: u64,
+        limit: i64,
+    ) -> Result<Vec<BlockHeader>, Box<dyn Error>>;
+    async fn save(&self, chain_id: u64, block_header: &BlockHeader) -> Result<(), Box<dyn Error>>;
+    async fn delete_above(&self, chain_id: u64, block_number: u64) -> Result<(), Box<dyn Error>>;
+    /// Drops every header of the chain but the `keep` most recent ones.
+    async fn prune(&self, chain_id: u64, keep: i64) -> Result<(), Box<dyn Error>>;
+}
@@ -2,10 +2,39 @@ use std::error::Error;
 
 use async_trait::async_trait;
 
-use crate::domain::entities::ethereum_event::EthereumEvent;
+use crate::domain::entities::{
+    ethereum_event::EthereumEvent,
+    ethereum_event_query::{EthereumEventPage, EthereumEventQuery},
+};
 
 #[async_trait]
 pub trait EthereumEventRepository: Send + Sync {
-    async fn list(&self) -> Result<Vec<EthereumEvent>, Box<dyn Error>>;
-    async fn insert_many(&self, ethereum_events: Vec<EthereumEvent>) -> Result<(), Box<dyn Error>>;
+    async fn list(&self, query: &EthereumEventQuery) -> Result<EthereumEventPage, Box<dyn Error>>;
+    async fn find_by_id(&self, id: &str) -> Result<Option<EthereumEvent>, Box<dyn Error>>;
+    /// Events committed after the `sequence`th, of the chain and type when set, in commit order.
+    async fn list_committed_after(
+        &self,
+        sequence: u64,
+        chain_id: Option<u64>,
+        event_type: Option<&str>,
+        limit: u64,
+    ) -> Result<Vec<EthereumEvent>, Box<dyn Error>>;
+    /// Sequence number of the last event committed, 0 when there is none.
+    async fn last_sequence(&self) -> Result<u64, Box<dyn Error>>;
+    /// Stores the events that are not stored yet and returns them, skipping the others.
+    async fn insert_many(
+        &self,
+        ethereum_events: Vec<EthereumEvent>,
+    ) -> Result<Vec<EthereumEvent>, Box<dyn Error>>;
+    /// Marks the chain's pending events up to `block_number` as finalized and returns how many were.
+    async fn finalize_up_to(&self, chain_id: u64, block_number: u64)
+    -> Result<u64, Box<dyn Error>>;
+    /// Deletes the chain's events above `block_number`, only those of `contract` when set, and
+    /// undoes their projections.
+    async fn delete_above_block(
+        &self,
+        chain_id: u64,
+        block_number: u64,
+        contract: Option<&str>,
+    ) -> Result<(), Box<dyn Error>>;
 }
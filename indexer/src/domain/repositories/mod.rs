@@ -1 +1,7 @@
+pub mod attestation_repository;
+pub mod badge_repository;
+pub mod badge_upvote_repository;
+pub mod block_cursor_repository;
+pub mod block_header_repository;
 pub mod ethereum_event_repository;
+pub mod token_balance_repository;
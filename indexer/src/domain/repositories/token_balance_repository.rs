@@ -0,0 +1,22 @@
+use std::error::Error;
+
+use alloy::primitives::Address;
+use async_trait::async_trait;
+
+use crate::domain::entities::token_balance::{TokenBalance, TopHoldersQuery};
+
+/// Read side of the token balance projection, which is updated alongside the ingested transfers.
+#[async_trait]
+pub trait TokenBalanceRepository: Send + Sync {
+    /// Balances of the address, one per chain it ever held tokens on.
+    async fn list_by_address(
+        &self,
+        chain_id: Option<u64>,
+        address: &Address,
+    ) -> Result<Vec<TokenBalance>, Box<dyn Error>>;
+    /// Accounts with a positive balance, largest first.
+    async fn top_holders(
+        &self,
+        query: &TopHoldersQuery,
+    ) -> Result<Vec<TokenBalance>, Box<dyn Error>>;
+}
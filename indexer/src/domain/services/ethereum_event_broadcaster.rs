@@ -0,0 +1,11 @@
+use tokio::sync::broadcast;
+
+use crate::domain::entities::ethereum_event::EthereumEvent;
+
+/// Fans newly committed events out to live subscribers.
+pub trait EthereumEventBroadcaster: Send + Sync {
+    fn publish(&self, ethereum_events: &[EthereumEvent]);
+    /// Receives the events published from now on. A receiver that falls too far behind
+    /// gets a `Lagged` error rather than blocking publishers.
+    fn subscribe(&self) -> broadcast::Receiver<EthereumEvent>;
+}
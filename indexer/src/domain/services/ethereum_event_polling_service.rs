@@ -1,9 +1,46 @@
 use async_trait::async_trait;
-use std::error::Error;
+use std::{error::Error, fmt, time::Duration};
 
-use crate::domain::entities::ethereum_event::EthereumEvent;
+use crate::domain::entities::{
+    block_header::BlockHeader, ethereum_event::EthereumEvent, indexed_contract::IndexedContract,
+};
+
+/// Returned by [`EthereumEventPollingService::poll`] when the provider rejects a block range
+/// because it holds too many logs. The same range split into smaller chunks can succeed.
+#[derive(Debug)]
+pub struct BlockRangeTooLarge(pub String);
+
+impl fmt::Display for BlockRangeTooLarge {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "Block range too large: {}", self.0)
+    }
+}
+
+impl Error for BlockRangeTooLarge {}
 
 #[async_trait]
 pub trait EthereumEventPollingService: Send + Sync {
-    async fn poll(&self) -> Result<Vec<EthereumEvent>, Box<dyn Error>>;
+    /// Contracts this service ingests events for, each tracked by its own cursor.
+    fn contracts(&self) -> Vec<IndexedContract>;
+    /// Chain this service is configured for, which tags every event it returns.
+    fn chain_id(&self) -> u64;
+    /// Blocks mined on top of an event's block before it is considered final.
+    fn confirmation_blocks(&self) -> u64;
+    /// Chain id reported by the node, to check it serves the configured chain.
+    async fn node_chain_id(&self) -> Result<u64, Box<dyn Error>>;
+    async fn latest_block_number(&self) -> Result<u64, Box<dyn Error>>;
+    /// Returns the canonical header at `block_number`, if the node knows about it.
+    async fn block_header(&self, block_number: u64) -> Result<Option<BlockHeader>, Box<dyn Error>>;
+    /// Fetches the events emitted by `contract` between `from_block` and `to_block`, both inclusive.
+    async fn poll(
+        &self,
+        contract: &IndexedContract,
+        from_block: u64,
+        to_block: u64,
+    ) -> Result<Vec<EthereumEvent>, Box<dyn Error>>;
+    /// Returns once new events may be available, or after `timeout` at the latest.
+    /// Services without push notifications simply wait for the timeout.
+    async fn wait_for_events(&self, timeout: Duration) {
+        tokio::time::sleep(timeout).await;
+    }
 }
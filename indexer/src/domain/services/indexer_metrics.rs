@@ -0,0 +1,22 @@
+use std::{error::Error, time::Duration};
+
+use crate::domain::entities::ethereum_event::EthereumEvent;
+
+/// Records how the indexer keeps up with the chains it follows.
+pub trait IndexerMetrics: Send + Sync {
+    /// Last block ingested for a contract, along with the chain head it is measured against.
+    fn record_contract_progress(
+        &self,
+        chain_id: u64,
+        contract: &str,
+        last_indexed_block: u64,
+        head_block: u64,
+    );
+    /// Counts the events that were new to storage, by type.
+    fn record_events_ingested(&self, chain_id: u64, ethereum_events: &[EthereumEvent]);
+    fn record_reorg(&self, chain_id: u64);
+    /// A single request sent to an RPC endpoint, whether it succeeded or not.
+    fn record_rpc_request(&self, chain_id: u64, method: &str, latency: Duration, failed: bool);
+    /// Renders every metric in the Prometheus text exposition format.
+    fn export(&self) -> Result<String, Box<dyn Error>>;
+}
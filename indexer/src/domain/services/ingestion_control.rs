@@ -0,0 +1,20 @@
+use async_trait::async_trait;
+use tokio::sync::OwnedMutexGuard;
+
+use crate::domain::entities::ingestion_status::{ContractActivity, IngestionError};
+
+/// Keeps the polling passes and admin operations of a chain from overlapping, and tracks how
+/// ingestion goes for operators.
+#[async_trait]
+pub trait IngestionControl: Send + Sync {
+    /// Waits for the chain's running polling pass or admin operation to end, and keeps any other
+    /// from starting until the guard is dropped.
+    async fn lock_chain(&self, chain_id: u64) -> OwnedMutexGuard<()>;
+    /// Blocks ingested for a contract by a polling pass, and the events among them that were new.
+    fn record_progress(&self, chain_id: u64, contract: &str, blocks: u64, events: usize);
+    /// Error that ended a polling pass while polling `contract`, or before any contract when unset.
+    fn record_error(&self, chain_id: u64, contract: Option<&str>, error: &str);
+    /// Last error recorded for the chain before any contract was polled.
+    fn last_chain_error(&self, chain_id: u64) -> Option<IngestionError>;
+    fn activity(&self, chain_id: u64, contract: &str) -> ContractActivity;
+}
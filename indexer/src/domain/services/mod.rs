@@ -1 +1,4 @@
+pub mod ethereum_event_broadcaster;
 pub mod ethereum_event_polling_service;
+pub mod indexer_metrics;
+pub mod ingestion_control;
@@ -1 +1,7 @@
+pub mod postgres_attestation_repository;
+pub mod postgres_badge_repository;
+pub mod postgres_badge_upvote_repository;
+pub mod postgres_block_cursor_repository;
+pub mod postgres_block_header_repository;
 pub mod postgres_ethereum_event_repository;
+pub mod postgres_token_balance_repository;
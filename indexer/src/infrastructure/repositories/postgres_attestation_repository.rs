@@ -0,0 +1,106 @@
+use std::error::Error;
+
+use alloy::primitives::B256;
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use sqlx::PgPool;
+
+use crate::domain::{
+    entities::attestation::{Attestation, AttestationFilter},
+    repositories::attestation_repository::AttestationRepository,
+};
+
+#[derive(Clone)]
+pub struct PostgresAttestationRepository {
+    pool: PgPool,
+}
+
+impl PostgresAttestationRepository {
+    pub fn new(pool: PgPool) -> Self {
+        Self { pool }
+    }
+}
+
+struct AttestationRow {
+    chain_id: i64,
+    uid: String,
+    attester: String,
+    recipient: String,
+    badge_name: String,
+    justification: String,
+    time: DateTime<Utc>,
+    revocation_time: Option<DateTime<Utc>>,
+    block_number: i64,
+}
+
+impl TryFrom<AttestationRow> for Attestation {
+    type Error = Box<dyn Error>;
+
+    fn try_from(row: AttestationRow) -> Result<Self, Self::Error> {
+        Ok(Attestation {
+            chain_id: row.chain_id as u64,
+            uid: row.uid.parse()?,
+            attester: row.attester.parse()?,
+            recipient: row.recipient.parse()?,
+            badge_name: row.badge_name,
+            justification: row.justification,
+            time: row.time,
+            revoked: row.revocation_time.is_some(),
+            revocation_time: row.revocation_time,
+            block_number: row.block_number as u64,
+        })
+    }
+}
+
+#[async_trait]
+impl AttestationRepository for PostgresAttestationRepository {
+    async fn list(&self, filter: &AttestationFilter) -> Result<Vec<Attestation>, Box<dyn Error>> {
+        let rows = sqlx::query_as!(
+            AttestationRow,
+            r#"
+            SELECT chain_id, uid, attester, recipient, badge_name, justification, time, revocation_time, block_number
+            FROM attestations
+            WHERE ($1::text IS NULL OR attester = $1)
+              AND ($2::text IS NULL OR recipient = $2)
+              AND ($3::text IS NULL OR badge_name = $3)
+              AND ($4 OR revocation_time IS NULL)
+              AND ($5::bigint IS NULL OR chain_id = $5)
+            ORDER BY chain_id, block_number, uid
+            "#,
+            filter.attester.map(|a| a.to_string()),
+            filter.recipient.map(|a| a.to_string()),
+            filter.badge_name,
+            filter.include_revoked,
+            filter.chain_id.map(|c| c as i64)
+        )
+        .fetch_all(&self.pool)
+        .await
+        .map_err(|e| Box::new(e) as Box<dyn std::error::Error>)?;
+
+        rows.into_iter().map(Attestation::try_from).collect()
+    }
+
+    async fn find_by_uid(
+        &self,
+        chain_id: Option<u64>,
+        uid: &B256,
+    ) -> Result<Option<Attestation>, Box<dyn Error>> {
+        let row = sqlx::query_as!(
+            AttestationRow,
+            r#"
+            SELECT chain_id, uid, attester, recipient, badge_name, justification, time, revocation_time, block_number
+            FROM attestations
+            WHERE ($1::bigint IS NULL OR chain_id = $1) AND uid = $2
+            ORDER BY chain_id
+            LIMIT 1
+            "#,
+            chain_id.map(|c| c as i64),
+            uid.to_string()
+        )
+        .fetch_optional(&self.pool)
+        .await
+        .map_err(|e| Box::new(e) as Box<dyn std::error::Error>)?;
+
+        row.map(Attestation::try_from).transpose()
+    }
+}
@@ -0,0 +1,111 @@
+use std::error::Error;
+
+use alloy::primitives::Address;
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use sqlx::PgPool;
+
+use crate::domain::{entities::badge::Badge, repositories::badge_repository::BadgeRepository};
+
+#[derive(Clone)]
+pub struct PostgresBadgeRepository {
+    pool: PgPool,
+}
+
+impl PostgresBadgeRepository {
+    pub fn new(pool: PgPool) -> Self {
+        Self { pool }
+    }
+}
+
+struct BadgeRow {
+    chain_id: i64,
+    name: String,
+    description: String,
+    creator: String,
+    block_number: i64,
+    timestamp: DateTime<Utc>,
+}
+
+impl TryFrom<BadgeRow> for Badge {
+    type Error = Box<dyn Error>;
+
+    fn try_from(row: BadgeRow) -> Result<Self, Self::Error> {
+        Ok(Badge {
+            chain_id: row.chain_id as u64,
+            name: row.name,
+            description: row.description,
+            creator: row.creator.parse()?,
+            block_number: row.block_number as u64,
+            timestamp: row.timestamp,
+        })
+    }
+}
+
+#[async_trait]
+impl BadgeRepository for PostgresBadgeRepository {
+    async fn list(&self, chain_id: Option<u64>) -> Result<Vec<Badge>, Box<dyn Error>> {
+        let rows = sqlx::query_as!(
+            BadgeRow,
+            r#"
+            SELECT chain_id, name, description, creator, block_number, timestamp
+            FROM badges
+            WHERE ($1::bigint IS NULL OR chain_id = $1)
+            ORDER BY chain_id, block_number, name
+            "#,
+            chain_id.map(|c| c as i64)
+        )
+        .fetch_all(&self.pool)
+        .await
+        .map_err(|e| Box::new(e) as Box<dyn std::error::Error>)?;
+
+        rows.into_iter().map(Badge::try_from).collect()
+    }
+
+    async fn find_by_name(
+        &self,
+        chain_id: Option<u64>,
+        name: &str,
+    ) -> Result<Option<Badge>, Box<dyn Error>> {
+        let row = sqlx::query_as!(
+            BadgeRow,
+            r#"
+            SELECT chain_id, name, description, creator, block_number, timestamp
+            FROM badges
+            WHERE ($1::bigint IS NULL OR chain_id = $1) AND name = $2
+            ORDER BY chain_id
+            LIMIT 1
+            "#,
+            chain_id.map(|c| c as i64),
+            name
+        )
+        .fetch_optional(&self.pool)
+        .await
+        .map_err(|e| Box::new(e) as Box<dyn std::error::Error>)?;
+
+        row.map(Badge::try_from).transpose()
+    }
+
+    async fn list_by_creator(
+        &self,
+        chain_id: Option<u64>,
+        creator: &Address,
+    ) -> Result<Vec<Badge>, Box<dyn Error>> {
+        let rows = sqlx::query_as!(
+            BadgeRow,
+            r#"
+            SELECT chain_id, name, description, creator, block_number, timestamp
+            FROM badges
+            WHERE ($1::bigint IS NULL OR chain_id = $1) AND creator = $2
+            ORDER BY chain_id, block_number, name
+            "#,
+            chain_id.map(|c| c as i64),
+            creator.to_string()
+        )
+        .fetch_all(&self.pool)
+        .await
+        .map_err(|e| Box::new(e) as Box<dyn std::error::Error>)?;
+
+        rows.into_iter().map(Badge::try_from).collect()
+    }
+}
@@ -0,0 +1,115 @@
+use std::error::Error;
+
+use alloy::primitives::Address;
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use sqlx::PgPool;
+
+use crate::domain::{
+    entities::{
+        badge_upvote::{BadgeRanking, BadgeRankingQuery, BadgeUpvote},
+        sort_order::SortOrder,
+    },
+    repositories::badge_upvote_repository::BadgeUpvoteRepository,
+};
+
+#[derive(Clone)]
+pub struct PostgresBadgeUpvoteRepository {
+    pool: PgPool,
+}
+
+impl PostgresBadgeUpvoteRepository {
+    pub fn new(pool: PgPool) -> Self {
+        Self { pool }
+    }
+}
+
+struct BadgeUpvoteRow {
+    chain_id: i64,
+    badge_name: String,
+    voter: String,
+    block_number: i64,
+    timestamp: DateTime<Utc>,
+}
+
+impl TryFrom<BadgeUpvoteRow> for BadgeUpvote {
+    type Error = Box<dyn Error>;
+
+    fn try_from(row: BadgeUpvoteRow) -> Result<Self, Self::Error> {
+        Ok(BadgeUpvote {
+            chain_id: row.chain_id as u64,
+            badge_name: row.badge_name,
+            voter: row.voter.parse()?,
+            block_number: row.block_number as u64,
+            timestamp: row.timestamp,
+        })
+    }
+}
+
+#[async_trait]
+impl BadgeUpvoteRepository for PostgresBadgeUpvoteRepository {
+    async fn ranking(
+        &self,
+        query: &BadgeRankingQuery,
+    ) -> Result<Vec<BadgeRanking>, Box<dyn Error>> {
+        // Badges without votes rank with zero upvotes, votes for unknown badges are still listed.
+        let rows = sqlx::query!(
+            r#"
+            SELECT
+                COALESCE(b.chain_id, c.chain_id) AS "chain_id!",
+                COALESCE(b.name, c.badge_name) AS "badge_name!",
+                COALESCE(c.upvotes, 0) AS "upvotes!"
+            FROM (SELECT chain_id, name FROM badges WHERE $4::bigint IS NULL OR chain_id = $4) b
+            FULL OUTER JOIN (
+                SELECT chain_id, badge_name, upvotes
+                FROM badge_vote_counts
+                WHERE $4::bigint IS NULL OR chain_id = $4
+            ) c ON c.chain_id = b.chain_id AND c.badge_name = b.name
+            ORDER BY
+                CASE WHEN $1 THEN COALESCE(c.upvotes, 0) END ASC,
+                CASE WHEN NOT $1 THEN COALESCE(c.upvotes, 0) END DESC,
+                2, 1
+            LIMIT $2 OFFSET $3
+            "#,
+            query.order == SortOrder::Asc,
+            query.limit as i64,
+            query.offset as i64,
+            query.chain_id.map(|c| c as i64)
+        )
+        .fetch_all(&self.pool)
+        .await
+        .map_err(|e| Box::new(e) as Box<dyn std::error::Error>)?;
+
+        Ok(rows
+            .into_iter()
+            .map(|row| BadgeRanking {
+                chain_id: row.chain_id as u64,
+                badge_name: row.badge_name,
+                upvotes: row.upvotes as u64,
+            })
+            .collect())
+    }
+
+    async fn list_by_voter(
+        &self,
+        chain_id: Option<u64>,
+        voter: &Address,
+    ) -> Result<Vec<BadgeUpvote>, Box<dyn Error>> {
+        let rows = sqlx::query_as!(
+            BadgeUpvoteRow,
+            r#"
+            SELECT chain_id, badge_name, voter, block_number, timestamp
+            FROM badge_upvotes
+            WHERE ($1::bigint IS NULL OR chain_id = $1) AND voter = $2
+            ORDER BY chain_id, block_number, badge_name
+            "#,
+            chain_id.map(|c| c as i64),
+            voter.to_string()
+        )
+        .fetch_all(&self.pool)
+        .await
+        .map_err(|e| Box::new(e) as Box<dyn std::error::Error>)?;
+
+        rows.into_iter().map(BadgeUpvote::try_from).collect()
+    }
+}
@@ -0,0 +1,164 @@
+use std::error::Error;
+
+use async_trait::async_trait;
+use sqlx::PgPool;
+
+use crate::domain::{
+    entities::block_cursor::BlockCursor,
+    repositories::block_cursor_repository::BlockCursorRepository,
+};
+
+#[derive(Clone)]
+pub struct PostgresBlockCursorRepository {
+    pool: PgPool,
+}
+
+impl PostgresBlockCursorRepository {
+    pub fn new(pool: PgPool) -> Self {
+        Self { pool }
+    }
+}
+
+#[async_trait]
+impl BlockCursorRepository for PostgresBlockCursorRepository {
+    async fn find_by_contract(
+        &self,
+        chain_id: u64,
+        contract: &str,
+    ) -> Result<Option<BlockCursor>, Box<dyn Error>> {
+        let row = sqlx::query!(
+            r#"
+            SELECT chain_id, contract, last_indexed_block, updated_at
+            FROM block_cursors
+            WHERE chain_id = $1 AND contract = $2
+            "#,
+            chain_id as i64,
+            contract
+        )
+        .fetch_optional(&self.pool)
+        .await
+        .map_err(|e| Box::new(e) as Box<dyn std::error::Error>)?;
+
+        Ok(row.map(|row| BlockCursor {
+            chain_id: row.chain_id as u64,
+            contract: row.contract,
+            last_indexed_block: row.last_indexed_block as u64,
+            updated_at: row.updated_at,
+        }))
+    }
+
+    async fn list_by_chain(&self, chain_id: u64) -> Result<Vec<BlockCursor>, Box<dyn Error>> {
+        let rows = sqlx::query!(
+            r#"
+            SELECT chain_id, contract, last_indexed_block, updated_at
+            FROM block_cursors
+            WHERE chain_id = $1
+            ORDER BY contract
+            "#,
+            chain_id as i64
+        )
+        .fetch_all(&self.pool)
+        .await
+        .map_err(|e| Box::new(e) as Box<dyn std::error::Error>)?;
+
+        Ok(rows
+            .into_iter()
+            .map(|row| BlockCursor {
+                chain_id: row.chain_id as u64,
+                contract: row.contract,
+                last_indexed_block: row.last_indexed_block as u64,
+                updated_at: row.updated_at,
+            })
+            .collect())
+    }
+
+    async fn save(&self, block_cursor: &BlockCursor) -> Result<(), Box<dyn Error>> {
+        sqlx::query!(
+            r#"
+            INSERT INTO block_cursors (chain_id, contract, last_indexed_block, updated_at)
+            VALUES ($1, $2, $3, $4)
+            ON CONFLICT (chain_id, contract)
+            DO UPDATE SET last_indexed_block = EXCLUDED.last_indexed_block,
+                          updated_at = EXCLUDED.updated_at
+            "#,
+            block_cursor.chain_id as i64,
+            block_cursor.contract,
+            block_cursor.last_indexed_block as i64,
+            block_cursor.updated_at
+        )
+        .execute(&self.pool)
+        .await
+        .map_err(|e| Box::new(e) as Box<dyn std::error::Error>)?;
+
+        Ok(())
+    }
+
+    async fn rewind(&self, chain_id: u64, block_number: u64) -> Result<(), Box<dyn Error>> {
+        sqlx::query!(
+            r#"
+            UPDATE block_cursors
+            SET last_indexed_block = $2, updated_at = NOW()
+            WHERE chain_id = $1 AND last_indexed_block > $2
+            "#,
+            chain_id as i64,
+            block_number as i64
+        )
+        .execute(&self.pool)
+        .await
+        .map_err(|e| Box::new(e) as Box<dyn std::error::Error>)?;
+
+        Ok(())
+    }
+
+    async fn set_paused(
+        &self,
+        chain_id: u64,
+        contract: &str,
+        paused: bool,
+    ) -> Result<(), Box<dyn Error>> {
+        if paused {
+            sqlx::query!(
+                r#"
+                INSERT INTO paused_contracts (chain_id, contract)
+                VALUES ($1, $2)
+                ON CONFLICT DO NOTHING
+                "#,
+                chain_id as i64,
+                contract
+            )
+            .execute(&self.pool)
+            .await
+        } else {
+            sqlx::query!(
+                r#"
+                DELETE FROM paused_contracts
+                WHERE chain_id = $1 AND contract = $2
+                "#,
+                chain_id as i64,
+                contract
+            )
+            .execute(&self.pool)
+            .await
+        }
+        .map_err(|e| Box::new(e) as Box<dyn std::error::Error>)?;
+
+        Ok(())
+    }
+
+    async fn list_paused(&self, chain_id: u64) -> Result<Vec<String>, Box<dyn Error>> {
+        let contracts = sqlx::query_scalar!(
+            r#"
+            SELECT contract
+            FROM paused_contracts
+            WHERE chain_id = $1
+            ORDER BY contract
+            "#,
+            chain_id as i64
+        )
+        .fetch_all(&self.pool)
+        .await
+        .map_err(|e| Box::new(e) as Box<dyn std::error::Error>)?;
+
+        Ok(contracts)
+    }
+}
@@ -0,0 +1,114 @@
+use std::error::Error;
+
+use async_trait::async_trait;
+use sqlx::PgPool;
+
+use crate::domain::{
+    entities::block_header::BlockHeader,
+    repositories::block_header_repository::BlockHeaderRepository,
+};
+
+#[derive(Clone)]
+pub struct PostgresBlockHeaderRepository {
+    pool: PgPool,
+}
+
+impl PostgresBlockHeaderRepository {
+    pub fn new(pool: PgPool) -> Self {
+        Self { pool }
+    }
+}
+
+#[async_trait]
+impl BlockHeaderRepository for PostgresBlockHeaderRepository {
+    async fn list_recent(
+        &self,
+        chain_id: u64,
+        limit: i64,
+    ) -> Result<Vec<BlockHeader>, Box<dyn Error>> {
+        let rows = sqlx::query!(
+            r#"
+            SELECT block_number, block_hash, parent_hash
+            FROM indexed_blocks
+            WHERE chain_id = $1
+            ORDER BY block_number DESC
+            LIMIT $2
+            "#,
+            chain_id as i64,
+            limit
+        )
+        .fetch_all(&self.pool)
+        .await
+        .map_err(|e| Box::new(e) as Box<dyn std::error::Error>)?;
+
+        Ok(rows
+            .into_iter()
+            .map(|row| BlockHeader {
+                number: row.block_number as u64,
+                hash: row.block_hash,
+                parent_hash: row.parent_hash,
+            })
+            .collect())
+    }
+
+    async fn save(&self, chain_id: u64, block_header: &BlockHeader) -> Result<(), Box<dyn Error>> {
+        sqlx::query!(
+            r#"
+            INSERT INTO indexed_blocks (chain_id, block_number, block_hash, parent_hash)
+            VALUES ($1, $2, $3, $4)
+            ON CONFLICT (chain_id, block_number)
+            DO UPDATE SET block_hash = EXCLUDED.block_hash,
+                          parent_hash = EXCLUDED.parent_hash,
+                          created_at = NOW()
+            "#,
+            chain_id as i64,
+            block_header.number as i64,
+            block_header.hash,
+            block_header.parent_hash
+        )
+        .execute(&self.pool)
+        .await
+        .map_err(|e| Box::new(e) as Box<dyn std::error::Error>)?;
+
+        Ok(())
+    }
+
+    async fn delete_above(&self, chain_id: u64, block_number: u64) -> Result<(), Box<dyn Error>> {
+        sqlx::query!(
+            r#"
+            DELETE FROM indexed_blocks
+            WHERE chain_id = $1 AND block_number > $2
+            "#,
+            chain_id as i64,
+            block_number as i64
+        )
+        .execute(&self.pool)
+        .await
+        .map_err(|e| Box::new(e) as Box<dyn std::error::Error>)?;
+
+        Ok(())
+    }
+
+    async fn prune(&self, chain_id: u64, keep: i64) -> Result<(), Box<dyn Error>> {
+        sqlx::query!(
+            r#"
+            DELETE FROM indexed_blocks
+            WHERE chain_id = $1
+              AND block_number NOT IN (
+                SELECT block_number
+                FROM indexed_blocks
+                WHERE chain_id = $1
+                ORDER BY block_number DESC
+                LIMIT $2
+            )
+            "#,
+            chain_id as i64,
+            keep
+        )
+        .execute(&self.pool)
+        .await
+        .map_err(|e| Box::new(e) as Box<dyn std::error::Error>)?;
+
+        Ok(())
+    }
+}
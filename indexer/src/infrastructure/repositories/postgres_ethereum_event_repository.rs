@@ -1,14 +1,25 @@
-use std::error::Error;
+use std::{collections::HashMap, error::Error};
 
 use async_trait::async_trait;
+use chrono::{DateTime, Utc};
 use sqlx::PgPool;
-use uuid::Uuid;
 
 use crate::domain::{
-    entities::ethereum_event::EthereumEvent,
+    entities::{
+        attestation::Attestation,
+        badge::Badge,
+        badge_upvote::BadgeUpvote,
+        ethereum_event::{EthereumEvent, EthereumEventType, EventStatus},
+        ethereum_event_query::{EthereumEventPage, EthereumEventQuery, EventCursor},
+        sort_order::SortOrder,
+        token_balance::TokenBalanceChange,
+    },
     repositories::ethereum_event_repository::EthereumEventRepository,
 };
 
+/// Advisory lock held by the transactions storing events.
+const EVENT_SEQUENCE_LOCK: i64 = 0x6576_656e_7473;
+
 #[derive(Clone)]
 pub struct PostgresEthereumEventRepository {
     pool: PgPool,
@@ -20,63 +31,655 @@ impl PostgresEthereumEventRepository {
     }
 }
 
+struct EthereumEventRow {
+    id: String,
+    chain_id: i64,
+    contract: String,
+    event_type: String,
+    payload: serde_json::Value,
+    tx_hash: String,
+    log_index: i64,
+    block_number: i64,
+    block_hash: String,
+    status: String,
+    timestamp: DateTime<Utc>,
+    created_at: DateTime<Utc>,
+    sequence: i64,
+}
+
+impl TryFrom<EthereumEventRow> for EthereumEvent {
+    type Error = Box<dyn Error>;
+
+    fn try_from(row: EthereumEventRow) -> Result<Self, Self::Error> {
+        Ok(EthereumEvent {
+            id: row.id,
+            event_type: EthereumEventType::from_parts(&row.event_type, row.payload)?,
+            chain_id: row.chain_id as u64,
+            contract: row.contract,
+            tx_hash: row.tx_hash,
+            log_index: row.log_index as u64,
+            block_number: row.block_number as u64,
+            block_hash: row.block_hash,
+            status: row.status.parse::<EventStatus>()?,
+            timestamp: row.timestamp,
+            created_at: row.created_at,
+            sequence: row.sequence as u64,
+        })
+    }
+}
+
 #[async_trait]
 impl EthereumEventRepository for PostgresEthereumEventRepository {
-    async fn list(&self) -> Result<Vec<EthereumEvent>, Box<dyn Error>> {
-        let rows = sqlx::query!(
+    async fn list(&self, query: &EthereumEventQuery) -> Result<EthereumEventPage, Box<dyn Error>> {
+        let ascending = query.order == SortOrder::Asc;
+        let cursor = query.cursor.as_ref();
+        let recipient = query.recipient.map(|address| address.to_checksum(None));
+        let creator = query.creator.map(|address| address.to_checksum(None));
+        let from_block = query.from_block.map(|block| block as i64);
+        let to_block = query.to_block.map(|block| block as i64);
+        let chain_id = query.chain_id.map(|chain_id| chain_id as i64);
+        let status = query.status.map(|status| status.as_str());
+        // One extra row tells whether there is a next page.
+        let limit = query.limit as i64 + 1;
+        // Each order has its own statement, so that both walk the position index.
+        let rows = if ascending {
+            sqlx::query_as!(
+                EthereumEventRow,
+                r#"
+                SELECT id, chain_id, contract, event_type, payload, tx_hash, log_index, block_number, block_hash, status, timestamp, created_at, sequence
+                FROM ethereum_events
+                WHERE ($1::text IS NULL OR event_type = $1)
+                  AND ($2::text IS NULL OR recipient = $2)
+                  AND ($3::text IS NULL OR creator = $3)
+                  AND ($4::bigint IS NULL OR block_number >= $4)
+                  AND ($5::bigint IS NULL OR block_number <= $5)
+                  AND ($6::timestamptz IS NULL OR timestamp >= $6)
+                  AND ($7::timestamptz IS NULL OR timestamp <= $7)
+                  AND ($12::bigint IS NULL OR chain_id = $12)
+                  AND ($13::text IS NULL OR status = $13)
+                  AND ($8::bigint IS NULL OR (block_number, log_index, id) > ($8, $9, $10))
+                ORDER BY block_number ASC, log_index ASC, id ASC
+                LIMIT $11
+                "#,
+                query.event_type,
+                recipient,
+                creator,
+                from_block,
+                to_block,
+                query.from_time,
+                query.to_time,
+                cursor.map(|cursor| cursor.block_number as i64),
+                cursor.map(|cursor| cursor.log_index as i64),
+                cursor.map(|cursor| cursor.id.clone()),
+                limit,
+                chain_id,
+                status
+            )
+            .fetch_all(&self.pool)
+            .await
+        } else {
+            sqlx::query_as!(
+                EthereumEventRow,
+                r#"
+                SELECT id, chain_id, contract, event_type, payload, tx_hash, log_index, block_number, block_hash, status, timestamp, created_at, sequence
+                FROM ethereum_events
+                WHERE ($1::text IS NULL OR event_type = $1)
+                  AND ($2::text IS NULL OR recipient = $2)
+                  AND ($3::text IS NULL OR creator = $3)
+                  AND ($4::bigint IS NULL OR block_number >= $4)
+                  AND ($5::bigint IS NULL OR block_number <= $5)
+                  AND ($6::timestamptz IS NULL OR timestamp >= $6)
+                  AND ($7::timestamptz IS NULL OR timestamp <= $7)
+                  AND ($12::bigint IS NULL OR chain_id = $12)
+                  AND ($13::text IS NULL OR status = $13)
+                  AND ($8::bigint IS NULL OR (block_number, log_index, id) < ($8, $9, $10))
+                ORDER BY block_number DESC, log_index DESC, id DESC
+                LIMIT $11
+                "#,
+                query.event_type,
+                recipient,
+                creator,
+                from_block,
+                to_block,
+                query.from_time,
+                query.to_time,
+                cursor.map(|cursor| cursor.block_number as i64),
+                cursor.map(|cursor| cursor.log_index as i64),
+                cursor.map(|cursor| cursor.id.clone()),
+                limit,
+                chain_id,
+                status
+            )
+            .fetch_all(&self.pool)
+            .await
+        }
+        .map_err(|e| Box::new(e) as Box<dyn std::error::Error>)?;
+
+        let mut events = rows
+            .into_iter()
+            .map(EthereumEvent::try_from)
+            .collect::<Result<Vec<_>, _>>()?;
+
+        let next_cursor = if events.len() as u64 > query.limit {
+            events.truncate(query.limit as usize);
+            events
+                .last()
+                .map(|event| EventCursor::from_event(event).to_string())
+        } else {
+            None
+        };
+
+        Ok(EthereumEventPage {
+            events,
+            next_cursor,
+        })
+    }
+
+    async fn find_by_id(&self, id: &str) -> Result<Option<EthereumEvent>, Box<dyn Error>> {
+        let row = sqlx::query_as!(
+            EthereumEventRow,
             r#"
-            SELECT id, event_type, timestamp, created_at
+            SELECT id, chain_id, contract, event_type, payload, tx_hash, log_index, block_number, block_hash, status, timestamp, created_at, sequence
             FROM ethereum_events
-            "#
+            WHERE id = $1
+            "#,
+            id
+        )
+        .fetch_optional(&self.pool)
+        .await
+        .map_err(|e| Box::new(e) as Box<dyn std::error::Error>)?;
+
+        row.map(EthereumEvent::try_from).transpose()
+    }
+
+    async fn list_committed_after(
+        &self,
+        sequence: u64,
+        chain_id: Option<u64>,
+        event_type: Option<&str>,
+        limit: u64,
+    ) -> Result<Vec<EthereumEvent>, Box<dyn Error>> {
+        let rows = sqlx::query_as!(
+            EthereumEventRow,
+            r#"
+            SELECT id, chain_id, contract, event_type, payload, tx_hash, log_index, block_number, block_hash, status, timestamp, created_at, sequence
+            FROM ethereum_events
+            WHERE sequence > $1
+              AND ($2::bigint IS NULL OR chain_id = $2)
+              AND ($3::text IS NULL OR event_type = $3)
+            ORDER BY sequence
+            LIMIT $4
+            "#,
+            sequence as i64,
+            chain_id.map(|chain_id| chain_id as i64),
+            event_type,
+            limit as i64
         )
         .fetch_all(&self.pool)
         .await
         .map_err(|e| Box::new(e) as Box<dyn std::error::Error>)?;
-        Ok(rows
-            .iter()
-            .map(|row| EthereumEvent {
-                id: row.id,
-                event_type: row.event_type.clone(),
-                timestamp: row.timestamp.clone(),
-                created_at: row.created_at.clone(),
-            })
-            .collect::<Vec<_>>())
+
+        rows.into_iter().map(EthereumEvent::try_from).collect()
     }
 
-    async fn insert_many(&self, ethereum_events: Vec<EthereumEvent>) -> Result<(), Box<dyn Error>> {
+    async fn last_sequence(&self) -> Result<u64, Box<dyn Error>> {
+        let sequence = sqlx::query_scalar!(
+            r#"SELECT COALESCE(MAX(sequence), 0) AS "sequence!" FROM ethereum_events"#
+        )
+        .fetch_one(&self.pool)
+        .await
+        .map_err(|e| Box::new(e) as Box<dyn std::error::Error>)?;
+
+        Ok(sequence as u64)
+    }
+
+    async fn insert_many(
+        &self,
+        ethereum_events: Vec<EthereumEvent>,
+    ) -> Result<Vec<EthereumEvent>, Box<dyn Error>> {
         if ethereum_events.is_empty() {
-            return Ok(());
+            return Ok(Vec::new());
         }
 
-        let ids: Vec<Uuid> = ethereum_events
+        let ids: Vec<String> = ethereum_events.iter().map(|e| e.id.clone()).collect();
+        let chain_ids: Vec<i64> = ethereum_events.iter().map(|e| e.chain_id as i64).collect();
+        let contracts: Vec<String> = ethereum_events.iter().map(|e| e.contract.clone()).collect();
+        let (event_types, payloads): (Vec<String>, Vec<serde_json::Value>) = ethereum_events
+            .iter()
+            .map(|e| e.event_type.to_parts())
+            .collect::<Result<Vec<_>, _>>()?
+            .into_iter()
+            .unzip();
+        let tx_hashes: Vec<String> = ethereum_events.iter().map(|e| e.tx_hash.clone()).collect();
+        let log_indexes: Vec<i64> = ethereum_events.iter().map(|e| e.log_index as i64).collect();
+        let block_numbers: Vec<i64> = ethereum_events
             .iter()
-            .map(|e| Uuid::parse_str(&e.id).unwrap())
+            .map(|e| e.block_number as i64)
             .collect();
-        let event_types: Vec<String> = ethereum_events
+        let block_hashes: Vec<String> = ethereum_events
             .iter()
-            .map(|e| format!("{:?}", e.event_type))
+            .map(|e| e.block_hash.clone())
+            .collect();
+        let statuses: Vec<String> = ethereum_events
+            .iter()
+            .map(|e| e.status.to_string())
             .collect();
         let timestamps: Vec<chrono::DateTime<chrono::Utc>> =
             ethereum_events.iter().map(|e| e.timestamp).collect();
         let created_ats: Vec<chrono::DateTime<chrono::Utc>> =
             ethereum_events.iter().map(|e| e.created_at).collect();
+        let recipients: Vec<Option<String>> = ethereum_events
+            .iter()
+            .map(|e| e.event_type.recipient().map(|a| a.to_checksum(None)))
+            .collect();
+        let creators: Vec<Option<String>> = ethereum_events
+            .iter()
+            .map(|e| e.event_type.creator().map(|a| a.to_checksum(None)))
+            .collect();
 
-        let rows = sqlx::query_as!(
-            EthereumEvent,
+        let mut tx = self
+            .pool
+            .begin()
+            .await
+            .map_err(|e| Box::new(e) as Box<dyn std::error::Error>)?;
+
+        // Sequence numbers are drawn and committed under one lock, so that they are committed in
+        // order and a stream that read up to a sequence number never sees a lower one appear.
+        sqlx::query!("SELECT pg_advisory_xact_lock($1)", EVENT_SEQUENCE_LOCK)
+            .execute(&mut *tx)
+            .await
+            .map_err(|e| Box::new(e) as Box<dyn std::error::Error>)?;
+
+        let inserted: HashMap<String, u64> = sqlx::query!(
             r#"
-            INSERT INTO ethereum_events (id, event_type, timestamp, created_at)
-            SELECT * FROM UNNEST($1::uuid[], $2::text[], $3::timestamptz[], $4::timestamptz[])
-            RETURNING id, event_type, timestamp, created_at
+            INSERT INTO ethereum_events (id, chain_id, event_type, payload, tx_hash, log_index, block_number, block_hash, timestamp, created_at, status, contract, recipient, creator)
+            SELECT * FROM UNNEST($1::text[], $2::bigint[], $3::text[], $4::jsonb[], $5::text[], $6::bigint[], $7::bigint[], $8::text[], $9::timestamptz[], $10::timestamptz[], $11::text[], $12::text[], $13::text[], $14::text[])
+            ON CONFLICT DO NOTHING
+            RETURNING id, sequence
             "#,
             &ids,
+            &chain_ids,
             &event_types,
+            &payloads,
+            &tx_hashes,
+            &log_indexes,
+            &block_numbers,
+            &block_hashes,
             &timestamps,
-            &created_ats
+            &created_ats,
+            &statuses,
+            &contracts,
+            &recipients as &[Option<String>],
+            &creators as &[Option<String>]
         )
-        .fetch_all(&self.pool)
+        .fetch_all(&mut *tx)
+        .await
+        .map_err(|e| Box::new(e) as Box<dyn std::error::Error>)?
+        .into_iter()
+        .map(|row| (row.id, row.sequence as u64))
+        .collect();
+
+        // Typed projections are written in the same transaction and cascade-deleted with their event.
+        let badges: Vec<(String, Badge)> = ethereum_events
+            .iter()
+            .filter_map(|e| Badge::from_event(e).map(|badge| (e.id.clone(), badge)))
+            .collect();
+        if !badges.is_empty() {
+            let event_ids: Vec<String> = badges.iter().map(|(id, _)| id.clone()).collect();
+            let badge_chain_ids: Vec<i64> = badges.iter().map(|(_, b)| b.chain_id as i64).collect();
+            let names: Vec<String> = badges.iter().map(|(_, b)| b.name.clone()).collect();
+            let descriptions: Vec<String> =
+                badges.iter().map(|(_, b)| b.description.clone()).collect();
+            let creators: Vec<String> = badges.iter().map(|(_, b)| b.creator.to_string()).collect();
+            let badge_block_numbers: Vec<i64> =
+                badges.iter().map(|(_, b)| b.block_number as i64).collect();
+            let badge_timestamps: Vec<chrono::DateTime<chrono::Utc>> =
+                badges.iter().map(|(_, b)| b.timestamp).collect();
+
+            sqlx::query!(
+                r#"
+                INSERT INTO badges (name, description, creator, event_id, block_number, timestamp, chain_id)
+                SELECT * FROM UNNEST($1::text[], $2::text[], $3::text[], $4::text[], $5::bigint[], $6::timestamptz[], $7::bigint[])
+                ON CONFLICT (chain_id, name) DO NOTHING
+                "#,
+                &names,
+                &descriptions,
+                &creators,
+                &event_ids,
+                &badge_block_numbers,
+                &badge_timestamps,
+                &badge_chain_ids
+            )
+            .execute(&mut *tx)
+            .await
+            .map_err(|e| Box::new(e) as Box<dyn std::error::Error>)?;
+        }
+
+        let attestations: Vec<(String, Attestation)> = ethereum_events
+            .iter()
+            .filter_map(|e| Attestation::from_event(e).map(|a| (e.id.clone(), a)))
+            .collect();
+        if !attestations.is_empty() {
+            let event_ids: Vec<String> = attestations.iter().map(|(id, _)| id.clone()).collect();
+            let attestation_chain_ids: Vec<i64> = attestations
+                .iter()
+                .map(|(_, a)| a.chain_id as i64)
+                .collect();
+            let uids: Vec<String> = attestations
+                .iter()
+                .map(|(_, a)| a.uid.to_string())
+                .collect();
+            let attesters: Vec<String> = attestations
+                .iter()
+                .map(|(_, a)| a.attester.to_string())
+                .collect();
+            let recipients: Vec<String> = attestations
+                .iter()
+                .map(|(_, a)| a.recipient.to_string())
+                .collect();
+            let badge_names: Vec<String> = attestations
+                .iter()
+                .map(|(_, a)| a.badge_name.clone())
+                .collect();
+            let justifications: Vec<String> = attestations
+                .iter()
+                .map(|(_, a)| a.justification.clone())
+                .collect();
+            let times: Vec<chrono::DateTime<chrono::Utc>> =
+                attestations.iter().map(|(_, a)| a.time).collect();
+            let attestation_block_numbers: Vec<i64> = attestations
+                .iter()
+                .map(|(_, a)| a.block_number as i64)
+                .collect();
+
+            sqlx::query!(
+                r#"
+                INSERT INTO attestations (uid, attester, recipient, badge_name, justification, time, event_id, block_number, chain_id)
+                SELECT * FROM UNNEST($1::text[], $2::text[], $3::text[], $4::text[], $5::text[], $6::timestamptz[], $7::text[], $8::bigint[], $9::bigint[])
+                ON CONFLICT (chain_id, uid) DO NOTHING
+                "#,
+                &uids,
+                &attesters,
+                &recipients,
+                &badge_names,
+                &justifications,
+                &times,
+                &event_ids,
+                &attestation_block_numbers,
+                &attestation_chain_ids
+            )
+            .execute(&mut *tx)
+            .await
+            .map_err(|e| Box::new(e) as Box<dyn std::error::Error>)?;
+        }
+
+        // Vote counts are materialized from the upvotes actually inserted, so replays do not double count.
+        let upvotes: Vec<(String, BadgeUpvote)> = ethereum_events
+            .iter()
+            .filter_map(|e| BadgeUpvote::from_event(e).map(|u| (e.id.clone(), u)))
+            .collect();
+        if !upvotes.is_empty() {
+            let event_ids: Vec<String> = upvotes.iter().map(|(id, _)| id.clone()).collect();
+            let upvote_chain_ids: Vec<i64> =
+                upvotes.iter().map(|(_, u)| u.chain_id as i64).collect();
+            let badge_names: Vec<String> =
+                upvotes.iter().map(|(_, u)| u.badge_name.clone()).collect();
+            let voters: Vec<String> = upvotes.iter().map(|(_, u)| u.voter.to_string()).collect();
+            let upvote_block_numbers: Vec<i64> =
+                upvotes.iter().map(|(_, u)| u.block_number as i64).collect();
+            let upvote_timestamps: Vec<chrono::DateTime<chrono::Utc>> =
+                upvotes.iter().map(|(_, u)| u.timestamp).collect();
+
+            sqlx::query!(
+                r#"
+                WITH inserted AS (
+                    INSERT INTO badge_upvotes (badge_name, voter, event_id, block_number, timestamp, chain_id)
+                    SELECT * FROM UNNEST($1::text[], $2::text[], $3::text[], $4::bigint[], $5::timestamptz[], $6::bigint[])
+                    ON CONFLICT (chain_id, badge_name, voter) DO NOTHING
+                    RETURNING chain_id, badge_name
+                )
+                INSERT INTO badge_vote_counts (chain_id, badge_name, upvotes)
+                SELECT chain_id, badge_name, COUNT(*) FROM inserted GROUP BY chain_id, badge_name
+                ON CONFLICT (chain_id, badge_name) DO UPDATE
+                SET upvotes = badge_vote_counts.upvotes + EXCLUDED.upvotes, updated_at = NOW()
+                "#,
+                &badge_names,
+                &voters,
+                &event_ids,
+                &upvote_block_numbers,
+                &upvote_timestamps,
+                &upvote_chain_ids
+            )
+            .execute(&mut *tx)
+            .await
+            .map_err(|e| Box::new(e) as Box<dyn std::error::Error>)?;
+        }
+
+        // Balances are adjusted by the transfers actually inserted, so replays do not double count.
+        let balance_changes: Vec<TokenBalanceChange> = ethereum_events
+            .iter()
+            .filter(|e| inserted.contains_key(&e.id))
+            .flat_map(TokenBalanceChange::from_event)
+            .collect();
+        if !balance_changes.is_empty() {
+            let change_chain_ids: Vec<i64> =
+                balance_changes.iter().map(|c| c.chain_id as i64).collect();
+            let addresses: Vec<String> = balance_changes
+                .iter()
+                .map(|c| c.address.to_string())
+                .collect();
+            let deltas: Vec<String> = balance_changes.iter().map(|c| c.delta()).collect();
+
+            sqlx::query!(
+                r#"
+                INSERT INTO token_balances (chain_id, address, balance)
+                SELECT chain_id, address, SUM(delta::numeric)
+                FROM UNNEST($1::bigint[], $2::text[], $3::text[]) AS c(chain_id, address, delta)
+                GROUP BY chain_id, address
+                ON CONFLICT (chain_id, address) DO UPDATE
+                SET balance = token_balances.balance + EXCLUDED.balance, updated_at = NOW()
+                "#,
+                &change_chain_ids,
+                &addresses,
+                &deltas
+            )
+            .execute(&mut *tx)
+            .await
+            .map_err(|e| Box::new(e) as Box<dyn std::error::Error>)?;
+        }
+
+        // Revocations only flag an existing attestation, so they are undone explicitly on rollback.
+        let revocations: Vec<(String, chrono::DateTime<chrono::Utc>, i64, i64)> = ethereum_events
+            .iter()
+            .filter_map(|e| match &e.event_type {
+                EthereumEventType::AttestationRevoked {
+                    uid,
+                    revocation_time,
+                    ..
+                } => Some((
+                    uid.to_string(),
+                    chrono::DateTime::from_timestamp(*revocation_time as i64, 0)
+                        .unwrap_or_default(),
+                    e.block_number as i64,
+                    e.chain_id as i64,
+                )),
+                _ => None,
+            })
+            .collect();
+        if !revocations.is_empty() {
+            let uids: Vec<String> = revocations
+                .iter()
+                .map(|(uid, _, _, _)| uid.clone())
+                .collect();
+            let revocation_times: Vec<chrono::DateTime<chrono::Utc>> =
+                revocations.iter().map(|(_, time, _, _)| *time).collect();
+            let revocation_block_numbers: Vec<i64> =
+                revocations.iter().map(|(_, _, block, _)| *block).collect();
+            let revocation_chain_ids: Vec<i64> = revocations
+                .iter()
+                .map(|(_, _, _, chain_id)| *chain_id)
+                .collect();
+
+            sqlx::query!(
+                r#"
+                UPDATE attestations
+                SET revocation_time = r.revocation_time,
+                    revocation_block_number = r.block_number
+                FROM UNNEST($1::text[], $2::timestamptz[], $3::bigint[], $4::bigint[])
+                    AS r(uid, revocation_time, block_number, chain_id)
+                WHERE attestations.chain_id = r.chain_id AND attestations.uid = r.uid
+                "#,
+                &uids,
+                &revocation_times,
+                &revocation_block_numbers,
+                &revocation_chain_ids
+            )
+            .execute(&mut *tx)
+            .await
+            .map_err(|e| Box::new(e) as Box<dyn std::error::Error>)?;
+        }
+
+        tx.commit()
+            .await
+            .map_err(|e| Box::new(e) as Box<dyn std::error::Error>)?;
+
+        let mut inserted_events: Vec<EthereumEvent> = ethereum_events
+            .into_iter()
+            .filter_map(|mut e| {
+                e.sequence = *inserted.get(&e.id)?;
+                Some(e)
+            })
+            .collect();
+        inserted_events.sort_by_key(|e| e.sequence);
+        Ok(inserted_events)
+    }
+
+    async fn finalize_up_to(
+        &self,
+        chain_id: u64,
+        block_number: u64,
+    ) -> Result<u64, Box<dyn Error>> {
+        let result = sqlx::query!(
+            r#"
+            UPDATE ethereum_events
+            SET status = 'finalized'
+            WHERE chain_id = $1 AND status = 'pending' AND block_number <= $2
+            "#,
+            chain_id as i64,
+            block_number as i64
+        )
+        .execute(&self.pool)
+        .await
+        .map_err(|e| Box::new(e) as Box<dyn std::error::Error>)?;
+
+        Ok(result.rows_affected())
+    }
+
+    async fn delete_above_block(
+        &self,
+        chain_id: u64,
+        block_number: u64,
+        contract: Option<&str>,
+    ) -> Result<(), Box<dyn Error>> {
+        let mut tx = self
+            .pool
+            .begin()
+            .await
+            .map_err(|e| Box::new(e) as Box<dyn std::error::Error>)?;
+
+        sqlx::query!(
+            r#"
+            UPDATE attestations
+            SET revocation_time = NULL, revocation_block_number = NULL
+            WHERE chain_id = $1 AND revocation_block_number > $2
+              AND ($3::text IS NULL OR event_id IN (
+                  SELECT id FROM ethereum_events WHERE chain_id = $1 AND contract = $3
+              ))
+            "#,
+            chain_id as i64,
+            block_number as i64,
+            contract
+        )
+        .execute(&mut *tx)
+        .await
+        .map_err(|e| Box::new(e) as Box<dyn std::error::Error>)?;
+
+        // Upvotes are deleted explicitly rather than by cascade so their counts can be decremented.
+        sqlx::query!(
+            r#"
+            WITH removed AS (
+                DELETE FROM badge_upvotes
+                WHERE chain_id = $1 AND block_number > $2
+                  AND ($3::text IS NULL OR event_id IN (
+                      SELECT id FROM ethereum_events WHERE chain_id = $1 AND contract = $3
+                  ))
+                RETURNING badge_name
+            )
+            UPDATE badge_vote_counts
+            SET upvotes = badge_vote_counts.upvotes - r.count, updated_at = NOW()
+            FROM (SELECT badge_name, COUNT(*) AS count FROM removed GROUP BY badge_name) AS r
+            WHERE badge_vote_counts.chain_id = $1 AND badge_vote_counts.badge_name = r.badge_name
+            "#,
+            chain_id as i64,
+            block_number as i64,
+            contract
+        )
+        .execute(&mut *tx)
         .await
         .map_err(|e| Box::new(e) as Box<dyn std::error::Error>)?;
 
+        // Balances are aggregates, so the transfers about to be deleted are reversed from their payloads.
+        sqlx::query!(
+            r#"
+            WITH removed AS (
+                SELECT event_type, payload
+                FROM ethereum_events
+                WHERE chain_id = $1 AND block_number > $2 AND ($3::text IS NULL OR contract = $3)
+            ),
+            changes AS (
+                SELECT payload->>'recipient' AS address, (payload->>'amount')::numeric AS delta
+                FROM removed
+                WHERE event_type = 'ActivityTokenMinted'
+                UNION ALL
+                SELECT payload->>'to', (payload->>'amount')::numeric
+                FROM removed
+                WHERE event_type = 'ActivityTokenTransferred'
+                UNION ALL
+                SELECT payload->>'from', -(payload->>'amount')::numeric
+                FROM removed
+                WHERE event_type = 'ActivityTokenTransferred'
+            )
+            UPDATE token_balances
+            SET balance = token_balances.balance - c.delta, updated_at = NOW()
+            FROM (SELECT address, SUM(delta) AS delta FROM changes GROUP BY address) AS c
+            WHERE token_balances.chain_id = $1 AND token_balances.address = c.address
+            "#,
+            chain_id as i64,
+            block_number as i64,
+            contract
+        )
+        .execute(&mut *tx)
+        .await
+        .map_err(|e| Box::new(e) as Box<dyn std::error::Error>)?;
+
+        // Projected rows referencing the deleted events are removed by ON DELETE CASCADE.
+        sqlx::query!(
+            r#"
+            DELETE FROM ethereum_events
+            WHERE chain_id = $1 AND block_number > $2 AND ($3::text IS NULL OR contract = $3)
+            "#,
+            chain_id as i64,
+            block_number as i64,
+            contract
+        )
+        .execute(&mut *tx)
+        .await
+        .map_err(|e| Box::new(e) as Box<dyn std::error::Error>)?;
+
+        tx.commit()
+            .await
+            .map_err(|e| Box::new(e) as Box<dyn std::error::Error>)?;
+
         Ok(())
     }
 }
@@ -0,0 +1,89 @@
+use std::error::Error;
+
+use alloy::primitives::Address;
+use async_trait::async_trait;
+use sqlx::PgPool;
+
+use crate::domain::{
+    entities::token_balance::{TokenBalance, TopHoldersQuery},
+    repositories::token_balance_repository::TokenBalanceRepository,
+};
+
+#[derive(Clone)]
+pub struct PostgresTokenBalanceRepository {
+    pool: PgPool,
+}
+
+impl PostgresTokenBalanceRepository {
+    pub fn new(pool: PgPool) -> Self {
+        Self { pool }
+    }
+}
+
+struct TokenBalanceRow {
+    chain_id: i64,
+    address: String,
+    balance: String,
+}
+
+impl TryFrom<TokenBalanceRow> for TokenBalance {
+    type Error = Box<dyn Error>;
+
+    fn try_from(row: TokenBalanceRow) -> Result<Self, Self::Error> {
+        Ok(TokenBalance::from_sum(
+            row.chain_id as u64,
+            row.address.parse()?,
+            &row.balance,
+        )?)
+    }
+}
+
+#[async_trait]
+impl TokenBalanceRepository for PostgresTokenBalanceRepository {
+    async fn list_by_address(
+        &self,
+        chain_id: Option<u64>,
+        address: &Address,
+    ) -> Result<Vec<TokenBalance>, Box<dyn Error>> {
+        let rows = sqlx::query_as!(
+            TokenBalanceRow,
+            r#"
+            SELECT chain_id, address, balance::text AS "balance!"
+            FROM token_balances
+            WHERE ($1::bigint IS NULL OR chain_id = $1) AND address = $2
+            ORDER BY chain_id
+            "#,
+            chain_id.map(|c| c as i64),
+            address.to_string()
+        )
+        .fetch_all(&self.pool)
+        .await
+        .map_err(|e| Box::new(e) as Box<dyn std::error::Error>)?;
+
+        rows.into_iter().map(TokenBalance::try_from).collect()
+    }
+
+    async fn top_holders(
+        &self,
+        query: &TopHoldersQuery,
+    ) -> Result<Vec<TokenBalance>, Box<dyn Error>> {
+        let rows = sqlx::query_as!(
+            TokenBalanceRow,
+            r#"
+            SELECT chain_id, address, balance::text AS "balance!"
+            FROM token_balances
+            WHERE ($1::bigint IS NULL OR chain_id = $1) AND balance > 0
+            ORDER BY balance DESC, chain_id, address
+            LIMIT $2 OFFSET $3
+            "#,
+            query.chain_id.map(|c| c as i64),
+            query.limit as i64,
+            query.offset as i64
+        )
+        .fetch_all(&self.pool)
+        .await
+        .map_err(|e| Box::new(e) as Box<dyn std::error::Error>)?;
+
+        rows.into_iter().map(TokenBalance::try_from).collect()
+    }
+}
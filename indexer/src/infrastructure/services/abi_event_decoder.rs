@@ -0,0 +1,212 @@
+use std::{collections::HashMap, error::Error, fs};
+
+use alloy::{
+    dyn_abi::{DynSolValue, EventExt},
+    hex,
+    json_abi::{ContractObject, Event, JsonAbi},
+    primitives::B256,
+    providers::DynProvider,
+    rpc::types::Log,
+};
+use async_trait::async_trait;
+use serde_json::{Map, Value};
+
+use crate::{
+    domain::entities::ethereum_event::EthereumEventType,
+    infrastructure::services::event_decoder::EventDecoder,
+};
+
+/// Decodes the events of a contract from its JSON ABI into [`EthereumEventType::Decoded`], so
+/// that a contract can be indexed from configuration alone.
+pub struct AbiEventDecoder {
+    contract: String,
+    events: HashMap<B256, Event>,
+}
+
+impl AbiEventDecoder {
+    /// Captures the named events of the ABI, or all of them when none are named. Overloaded
+    /// events are captured under every signature sharing the name.
+    pub fn new(
+        contract: &str,
+        abi: &JsonAbi,
+        event_names: &[String],
+    ) -> Result<Self, Box<dyn Error>> {
+        let mut events = Vec::new();
+        if event_names.is_empty() {
+            events.extend(abi.events().cloned());
+        }
+        for name in event_names {
+            let overloads = abi
+                .event(name)
+                .ok_or_else(|| format!("No event {name} in the ABI of contract {contract}"))?;
+            events.extend(overloads.iter().cloned());
+        }
+        if events.is_empty() {
+            return Err(format!("No event in the ABI of contract {contract}").into());
+        }
+        if let Some(event) = events.iter().find(|event| event.anonymous) {
+            return Err(format!(
+                "Anonymous event {} of contract {contract} cannot be filtered by signature",
+                event.name
+            )
+            .into());
+        }
+
+        Ok(Self {
+            contract: contract.to_string(),
+            events: events
+                .into_iter()
+                .map(|event| (event.selector(), event))
+                .collect(),
+        })
+    }
+
+    /// Reads the ABI from a file holding either the bare ABI array or a compiler artifact with
+    /// an `abi` field, as found in `contracts/`.
+    pub fn from_file(
+        contract: &str,
+        path: &str,
+        event_names: &[String],
+    ) -> Result<Self, Box<dyn Error>> {
+        let content =
+            fs::read_to_string(path).map_err(|e| format!("Could not read ABI file {path}: {e}"))?;
+        let abi = serde_json::from_str::<ContractObject>(&content)
+            .map_err(|e| format!("Invalid ABI file {path}: {e}"))?
+            .abi
+            .ok_or_else(|| format!("No ABI in file {path}"))?;
+        Self::new(contract, &abi, event_names)
+    }
+}
+
+#[async_trait]
+impl EventDecoder for AbiEventDecoder {
+    fn event_signatures(&self) -> Vec<B256> {
+        self.events.keys().copied().collect()
+    }
+
+    async fn decode(
+        &self,
+        log: &Log,
+        _provider: &DynProvider,
+    ) -> Result<Option<EthereumEventType>, Box<dyn Error>> {
+        let Some(event) = log.topic0().and_then(|topic| self.events.get(topic)) else {
+            return Ok(None);
+        };
+        let decoded = event.decode_log(log.data())?;
+
+        // Indexed and non-indexed parameters are decoded apart, each group in declaration order.
+        let mut indexed = decoded.indexed.iter();
+        let mut body = decoded.body.iter();
+        let mut params = Map::new();
+        for (position, input) in event.inputs.iter().enumerate() {
+            let value = if input.indexed {
+                indexed.next()
+            } else {
+                body.next()
+            };
+            let name = if input.name.is_empty() {
+                position.to_string()
+            } else {
+                input.name.clone()
+            };
+            params.insert(name, value.map_or(Value::Null, to_json));
+        }
+
+        Ok(Some(EthereumEventType::Decoded {
+            contract: self.contract.clone(),
+            event: event.name.clone(),
+            params,
+        }))
+    }
+}
+
+/// Renders values the way the payloads of the built-in events are: checksummed addresses, and
+/// integers as decimal strings so they stay exact. Indexed strings, bytes and arrays only come
+/// as the hash stored in their topic.
+fn to_json(value: &DynSolValue) -> Value {
+    match value {
+        DynSolValue::Bool(value) => Value::Bool(*value),
+        DynSolValue::Int(value, _) => Value::String(value.to_string()),
+        DynSolValue::Uint(value, _) => Value::String(value.to_string()),
+        DynSolValue::FixedBytes(word, size) => Value::String(hex::encode_prefixed(&word[..*size])),
+        DynSolValue::Address(address) => Value::String(address.to_checksum(None)),
+        DynSolValue::Function(function) => Value::String(function.to_string()),
+        DynSolValue::Bytes(bytes) => Value::String(hex::encode_prefixed(bytes)),
+        DynSolValue::String(value) => Value::String(value.clone()),
+        DynSolValue::Array(values)
+        | DynSolValue::FixedArray(values)
+        | DynSolValue::Tuple(values) => Value::Array(values.iter().map(to_json).collect()),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use alloy::{
+        primitives::{Address, LogData, U256},
+        providers::{Provider, ProviderBuilder},
+        sol_types::SolValue,
+        transports::mock::Asserter,
+    };
+    use serde_json::json;
+
+    use super::*;
+
+    #[tokio::test]
+    async fn decodes_the_parameters_of_the_captured_events() {
+        let abi: JsonAbi = serde_json::from_value(json!([
+            {
+                "type": "event",
+                "name": "MemberAdded",
+                "anonymous": false,
+                "inputs": [
+                    { "name": "member", "type": "address", "indexed": true },
+                    { "name": "role", "type": "bytes32", "indexed": false },
+                    { "name": "weight", "type": "uint256", "indexed": false },
+                ],
+            },
+            {
+                "type": "event",
+                "name": "MemberRemoved",
+                "anonymous": false,
+                "inputs": [{ "name": "member", "type": "address", "indexed": true }],
+            },
+        ]))
+        .unwrap();
+        let decoder = AbiEventDecoder::new("members", &abi, &["MemberAdded".to_string()]).unwrap();
+        assert_eq!(decoder.event_signatures().len(), 1);
+
+        let member = Address::repeat_byte(0xab);
+        let log = Log {
+            inner: alloy::primitives::Log {
+                address: Address::repeat_byte(1),
+                data: LogData::new_unchecked(
+                    vec![decoder.event_signatures()[0], member.into_word()],
+                    (B256::repeat_byte(0x61), U256::from(3))
+                        .abi_encode_params()
+                        .into(),
+                ),
+            },
+            ..Default::default()
+        };
+        let provider = ProviderBuilder::new()
+            .connect_mocked_client(Asserter::new())
+            .erased();
+
+        let event_type = decoder.decode(&log, &provider).await.unwrap().unwrap();
+        assert_eq!(
+            event_type,
+            EthereumEventType::Decoded {
+                contract: "members".to_string(),
+                event: "MemberAdded".to_string(),
+                params: json!({
+                    "member": member.to_checksum(None),
+                    "role": format!("0x{}", "61".repeat(32)),
+                    "weight": "3",
+                })
+                .as_object()
+                .unwrap()
+                .clone(),
+            }
+        );
+    }
+}
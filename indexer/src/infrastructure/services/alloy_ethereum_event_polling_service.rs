@@ -1,64 +1,349 @@
-use std::error::Error;
+use std::{
+    collections::{HashMap, HashSet},
+    error::Error,
+    sync::{Arc, Mutex},
+};
 
 use alloy::{
-    primitives::Address,
-    providers::{Provider, ProviderBuilder},
-    rpc::types::Filter,
-    sol,
-    sol_types::SolEvent,
+    eips::BlockNumberOrTag,
+    primitives::B256,
+    providers::{DynProvider, Provider, ProviderBuilder},
+    rpc::{
+        client::RpcClient,
+        types::{Filter, Log},
+    },
+    transports::{BoxTransport, http::Http, utils::guess_local_url},
 };
 use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use futures::{StreamExt, stream};
 
-use crate::domain::{
-    entities::ethereum_event::EthereumEvent,
-    services::ethereum_event_polling_service::EthereumEventPollingService,
+use crate::{
+    domain::{
+        entities::{
+            block_header::BlockHeader, ethereum_event::EthereumEvent,
+            indexed_contract::IndexedContract,
+        },
+        services::{
+            ethereum_event_polling_service::{BlockRangeTooLarge, EthereumEventPollingService},
+            indexer_metrics::IndexerMetrics,
+        },
+    },
+    infrastructure::services::{
+        block_timestamp_cache::BlockTimestampCache,
+        event_decoder::EventDecoderRegistry,
+        resilient_transport::{ResilientTransport, RpcResilienceConfig},
+    },
 };
 
+/// Fragments of the errors providers return when `eth_getLogs` would yield too many results.
+/// Generic wordings such as "limit exceeded" are left out, since providers word their rate
+/// limits and quotas alike and a smaller range would not help with those.
+const TOO_MANY_LOGS_ERRORS: &[&str] = &[
+    "query returned more than",
+    "too many results",
+    "log response size exceeded",
+    "block range is too large",
+    "block range too large",
+    "block range is too wide",
+    "range is too large",
+    "exceed maximum block range",
+];
+
+/// Whether the provider refused `eth_getLogs` because the range yields too many results.
+pub(crate) fn is_too_many_logs_error(message: &str) -> bool {
+    let lowercase = message.to_lowercase();
+    TOO_MANY_LOGS_ERRORS
+        .iter()
+        .any(|pattern| lowercase.contains(pattern))
+}
+
+/// Blocks whose timestamp is remembered, enough to cover a few polls of busy contracts.
+const BLOCK_TIMESTAMP_CACHE_SIZE: usize = 1024;
+
+/// Block headers fetched at once when logs come without their block's timestamp.
+const BLOCK_FETCH_CONCURRENCY: usize = 8;
+
 #[derive(Clone)]
-pub struct AlloyEthereumEventPollingService {}
+pub struct AlloyEthereumEventPollingService {
+    provider: DynProvider,
+    chain_id: u64,
+    confirmation_blocks: u64,
+    contracts: Vec<IndexedContract>,
+    decoders: EventDecoderRegistry,
+    block_timestamps: Arc<Mutex<BlockTimestampCache>>,
+}
 
 impl AlloyEthereumEventPollingService {
-    pub fn new() -> Self {
-        Self {}
+    /// Sends requests to the RPC endpoints in order of preference, failing over and retrying
+    /// as described by the resilience settings. Each contract's logs are decoded by the decoder
+    /// registered under its name.
+    pub fn new(
+        rpc_urls: &[String],
+        chain_id: u64,
+        confirmation_blocks: u64,
+        contracts: Vec<IndexedContract>,
+        decoders: EventDecoderRegistry,
+        resilience: RpcResilienceConfig,
+        indexer_metrics: Arc<dyn IndexerMetrics>,
+    ) -> Result<Self, Box<dyn Error>> {
+        let mut endpoints = Vec::new();
+        for rpc_url in rpc_urls {
+            endpoints.push((
+                rpc_url.clone(),
+                BoxTransport::new(Http::new(rpc_url.parse()?)),
+            ));
+        }
+        let is_local = rpc_urls.iter().all(guess_local_url);
+        let provider = ProviderBuilder::new()
+            .connect_client(RpcClient::new(
+                ResilientTransport::new(endpoints, resilience, chain_id, indexer_metrics),
+                is_local,
+            ))
+            .erased();
+        Ok(Self {
+            provider,
+            chain_id,
+            confirmation_blocks,
+            contracts,
+            decoders,
+            block_timestamps: Arc::new(Mutex::new(BlockTimestampCache::new(
+                BLOCK_TIMESTAMP_CACHE_SIZE,
+            ))),
+        })
+    }
+
+    /// Timestamps of the blocks the logs were emitted in, keyed by block hash. Taken from the
+    /// logs when the node includes them, otherwise from the block headers, which are fetched
+    /// concurrently and cached.
+    async fn block_timestamps(
+        &self,
+        logs: &[Log],
+    ) -> Result<HashMap<B256, DateTime<Utc>>, Box<dyn Error>> {
+        let mut timestamps = HashMap::new();
+        let mut missing = HashSet::new();
+        {
+            let cache = self.block_timestamps.lock().unwrap();
+            for log in logs {
+                let Some(block_hash) = log.block_hash else {
+                    continue;
+                };
+                let timestamp = log
+                    .block_timestamp
+                    .and_then(|timestamp| DateTime::from_timestamp(timestamp as i64, 0))
+                    .or_else(|| cache.get(&block_hash));
+                match timestamp {
+                    Some(timestamp) => {
+                        timestamps.insert(block_hash, timestamp);
+                    }
+                    None => {
+                        missing.insert(block_hash);
+                    }
+                }
+            }
+        }
+
+        let fetched: Vec<_> = stream::iter(missing)
+            .map(|block_hash| async move {
+                (
+                    block_hash,
+                    self.provider.get_block_by_hash(block_hash).await,
+                )
+            })
+            .buffer_unordered(BLOCK_FETCH_CONCURRENCY)
+            .collect()
+            .await;
+
+        let mut cache = self.block_timestamps.lock().unwrap();
+        for (block_hash, block) in fetched {
+            let block = block?.ok_or_else(|| format!("Block {block_hash} not found"))?;
+            let timestamp =
+                DateTime::from_timestamp(block.header.timestamp as i64, 0).unwrap_or_default();
+            cache.insert(block_hash, timestamp);
+            timestamps.insert(block_hash, timestamp);
+        }
+
+        Ok(timestamps)
+    }
+
+    async fn get_logs(&self, filter: Filter) -> Result<Vec<Log>, Box<dyn Error>> {
+        self.provider.get_logs(&filter).await.map_err(|e| {
+            let message = e.to_string();
+            if is_too_many_logs_error(&message) {
+                Box::new(BlockRangeTooLarge(message)) as Box<dyn Error>
+            } else {
+                e.into()
+            }
+        })
     }
-}
 
-sol!(ITokenActivity, "contracts/activity_token_abi.json");
+    /// Filter for the contract's logs of the given events in the requested block range.
+    fn filter(
+        contract: &IndexedContract,
+        event_signatures: Vec<B256>,
+        from_block: u64,
+        to_block: u64,
+    ) -> Filter {
+        Filter::new()
+            .address(contract.address)
+            .event_signature(event_signatures)
+            .from_block(from_block)
+            .to_block(to_block)
+    }
+}
 
 #[async_trait]
 impl EthereumEventPollingService for AlloyEthereumEventPollingService {
-    async fn poll(&self) -> Result<Vec<EthereumEvent>, Box<dyn Error>> {
-        let rpc_url = "https://reth-ethereum.ithaca.xyz/rpc".parse()?;
-        let provider = ProviderBuilder::new().connect_http(rpc_url);
+    fn contracts(&self) -> Vec<IndexedContract> {
+        self.contracts.clone()
+    }
+
+    fn chain_id(&self) -> u64 {
+        self.chain_id
+    }
 
-        // Get logs from the latest block
-        let latest_block = provider.get_block_number().await?;
+    fn confirmation_blocks(&self) -> u64 {
+        self.confirmation_blocks
+    }
 
-        // Create a filter to get all logs from the latest block.
-        let filter = Filter::new()
-            .from_block(latest_block)
-            .event("Transfer(from,to,value)");
+    async fn node_chain_id(&self) -> Result<u64, Box<dyn Error>> {
+        Ok(self.provider.get_chain_id().await?)
+    }
 
-        // Get all logs from the latest block that match the filter.
-        let logs = provider.get_logs(&filter).await?;
+    async fn latest_block_number(&self) -> Result<u64, Box<dyn Error>> {
+        Ok(self.provider.get_block_number().await?)
+    }
 
-        let mut events: Vec<EthereumEvent> = Vec::<EthereumEvent>::new();
+    async fn block_header(&self, block_number: u64) -> Result<Option<BlockHeader>, Box<dyn Error>> {
+        let block = self
+            .provider
+            .get_block_by_number(BlockNumberOrTag::Number(block_number))
+            .await?;
+        Ok(block.map(|block| BlockHeader {
+            number: block.header.number,
+            hash: block.header.hash.to_string(),
+            parent_hash: block.header.parent_hash.to_string(),
+        }))
+    }
 
+    async fn poll(
+        &self,
+        contract: &IndexedContract,
+        from_block: u64,
+        to_block: u64,
+    ) -> Result<Vec<EthereumEvent>, Box<dyn Error>> {
+        let decoder = self
+            .decoders
+            .get(&contract.name)
+            .ok_or_else(|| format!("No event decoder for contract {}", contract.name))?;
+        let logs = self
+            .get_logs(decoder.restrict(Self::filter(
+                contract,
+                decoder.event_signatures(),
+                from_block,
+                to_block,
+            )))
+            .await?;
+        let timestamps = self.block_timestamps(&logs).await?;
+
+        let mut events = Vec::new();
         for log in logs {
-            let transfer = ITokenActivity::Transfer::decode_log_data(log.data())?;
-            if transfer.from == Address::ZERO {
-                let tx_hash = log.transaction_hash.unwrap();
-                let log_index = log.log_index.unwrap();
-                let event = EthereumEvent::new_activity_token_minted_from_transfer_data(
-                    tx_hash.to_string(),
-                    log_index.to_string(),
-                    transfer.to,
-                    transfer.value,
-                );
-                events.push(event);
-            }
+            let Some(event_type) = decoder.decode(&log, &self.provider).await? else {
+                continue;
+            };
+            // Pending logs have no position yet, the range is polled again rather than skipped
+            let (Some(tx_hash), Some(log_index), Some(block_number), Some(block_hash)) = (
+                log.transaction_hash,
+                log.log_index,
+                log.block_number,
+                log.block_hash,
+            ) else {
+                return Err(format!(
+                    "Log of contract {} in blocks {from_block}..={to_block} has no position",
+                    contract.name
+                )
+                .into());
+            };
+            let timestamp = timestamps
+                .get(&block_hash)
+                .copied()
+                .ok_or_else(|| format!("No timestamp for block {block_hash}"))?;
+            events.push(EthereumEvent::new(
+                self.chain_id,
+                contract.name.clone(),
+                tx_hash.to_string(),
+                log_index,
+                block_number,
+                block_hash.to_string(),
+                timestamp,
+                event_type,
+            ));
         }
 
         Ok(events)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use alloy::{
+        json_abi::JsonAbi,
+        primitives::{Address, LogData},
+        transports::mock::Asserter,
+    };
+    use serde_json::json;
+
+    use super::*;
+    use crate::infrastructure::services::abi_event_decoder::AbiEventDecoder;
+
+    #[tokio::test]
+    async fn fails_the_poll_on_logs_without_a_position_rather_than_panicking() {
+        let abi: JsonAbi = serde_json::from_value(json!([{
+            "type": "event",
+            "name": "Ping",
+            "anonymous": false,
+            "inputs": [],
+        }]))
+        .unwrap();
+        let decoder = AbiEventDecoder::new("pings", &abi, &[]).unwrap();
+        let contract = IndexedContract {
+            name: "pings".to_string(),
+            address: Address::repeat_byte(1),
+            start_block: None,
+            schema_id: None,
+            abi: None,
+            events: Vec::new(),
+        };
+        let mut decoders = EventDecoderRegistry::default();
+        decoders.register("pings", Arc::new(decoder));
+
+        // As served by some nodes for logs of a block that is still pending
+        let pending = Log {
+            inner: alloy::primitives::Log {
+                address: contract.address,
+                data: LogData::new_unchecked(
+                    vec![abi.events["Ping"][0].selector()],
+                    Default::default(),
+                ),
+            },
+            block_number: Some(10),
+            transaction_hash: Some(B256::repeat_byte(2)),
+            log_index: Some(0),
+            ..Default::default()
+        };
+        let asserter = Asserter::new();
+        asserter.push_success(&vec![pending]);
+        let service = AlloyEthereumEventPollingService {
+            provider: ProviderBuilder::new()
+                .connect_mocked_client(asserter)
+                .erased(),
+            chain_id: 80002,
+            confirmation_blocks: 0,
+            contracts: vec![contract.clone()],
+            decoders,
+            block_timestamps: Arc::new(Mutex::new(BlockTimestampCache::new(1))),
+        };
+
+        assert!(service.poll(&contract, 10, 10).await.is_err());
+    }
+}
@@ -0,0 +1,144 @@
+use std::{
+    error::Error,
+    sync::{Arc, Once},
+    time::Duration,
+};
+
+use alloy::{
+    providers::{Provider, ProviderBuilder, WsConnect},
+    rpc::types::Filter,
+};
+use async_trait::async_trait;
+use tokio::sync::{Notify, broadcast::error::RecvError};
+
+use crate::{
+    domain::{
+        entities::{
+            block_header::BlockHeader, ethereum_event::EthereumEvent,
+            indexed_contract::IndexedContract,
+        },
+        services::ethereum_event_polling_service::EthereumEventPollingService,
+    },
+    infrastructure::services::alloy_ethereum_event_polling_service::AlloyEthereumEventPollingService,
+};
+
+const MIN_RECONNECT_DELAY: Duration = Duration::from_secs(1);
+const MAX_RECONNECT_DELAY: Duration = Duration::from_secs(60);
+
+/// Subscribes to the logs of the indexed contracts over a WebSocket to wake the polling loop
+/// as soon as one of them emits an event.
+///
+/// Notifications carry no data: events are still fetched by range from the persisted cursor
+/// through the wrapped HTTP service, so a dropped connection or a missed notification only
+/// delays ingestion until the next timed poll, and the gap is filled from the cursor.
+pub struct AlloyPubsubEthereumEventPollingService {
+    http: AlloyEthereumEventPollingService,
+    ws_url: String,
+    notify: Arc<Notify>,
+    subscribed: Once,
+}
+
+impl AlloyPubsubEthereumEventPollingService {
+    pub fn new(ws_url: &str, http: AlloyEthereumEventPollingService) -> Self {
+        Self {
+            http,
+            ws_url: ws_url.to_string(),
+            notify: Arc::new(Notify::new()),
+            subscribed: Once::new(),
+        }
+    }
+
+    /// Keeps a log subscription open, reconnecting with a growing delay when it drops.
+    async fn subscribe(ws_url: String, filter: Filter, notify: Arc<Notify>) {
+        let mut delay = MIN_RECONNECT_DELAY;
+        loop {
+            match Self::forward_notifications(&ws_url, &filter, &notify).await {
+                Ok(()) => {
+                    tracing::warn!("Log subscription closed, falling back to polling");
+                    delay = MIN_RECONNECT_DELAY;
+                }
+                Err(e) => {
+                    tracing::warn!("Log subscription failed, falling back to polling: {}", e);
+                }
+            }
+            // Catch up on whatever was missed while disconnected.
+            notify.notify_one();
+            tokio::time::sleep(delay).await;
+            delay = MAX_RECONNECT_DELAY.min(delay * 2);
+        }
+    }
+
+    async fn forward_notifications(
+        ws_url: &str,
+        filter: &Filter,
+        notify: &Notify,
+    ) -> Result<(), Box<dyn Error + Send + Sync>> {
+        let provider = ProviderBuilder::new()
+            .connect_ws(WsConnect::new(ws_url))
+            .await?;
+        let mut subscription = provider.subscribe_logs(filter).await?;
+        tracing::info!("Subscribed to logs over {}", ws_url);
+
+        loop {
+            match subscription.recv().await {
+                Ok(_) | Err(RecvError::Lagged(_)) => notify.notify_one(),
+                Err(RecvError::Closed) => return Ok(()),
+            }
+        }
+    }
+}
+
+#[async_trait]
+impl EthereumEventPollingService for AlloyPubsubEthereumEventPollingService {
+    fn contracts(&self) -> Vec<IndexedContract> {
+        self.http.contracts()
+    }
+
+    fn chain_id(&self) -> u64 {
+        self.http.chain_id()
+    }
+
+    fn confirmation_blocks(&self) -> u64 {
+        self.http.confirmation_blocks()
+    }
+
+    async fn node_chain_id(&self) -> Result<u64, Box<dyn Error>> {
+        self.http.node_chain_id().await
+    }
+
+    async fn latest_block_number(&self) -> Result<u64, Box<dyn Error>> {
+        self.http.latest_block_number().await
+    }
+
+    async fn block_header(&self, block_number: u64) -> Result<Option<BlockHeader>, Box<dyn Error>> {
+        self.http.block_header(block_number).await
+    }
+
+    async fn poll(
+        &self,
+        contract: &IndexedContract,
+        from_block: u64,
+        to_block: u64,
+    ) -> Result<Vec<EthereumEvent>, Box<dyn Error>> {
+        self.http.poll(contract, from_block, to_block).await
+    }
+
+    async fn wait_for_events(&self, timeout: Duration) {
+        // Subscribing lazily keeps one-off commands such as backfills off the WebSocket.
+        self.subscribed.call_once(|| {
+            let filter = Filter::new().address(
+                self.contracts()
+                    .iter()
+                    .map(|contract| contract.address)
+                    .collect::<Vec<_>>(),
+            );
+            tokio::spawn(Self::subscribe(
+                self.ws_url.clone(),
+                filter,
+                self.notify.clone(),
+            ));
+        });
+
+        let _ = tokio::time::timeout(timeout, self.notify.notified()).await;
+    }
+}
@@ -0,0 +1,58 @@
+use std::collections::{HashMap, VecDeque};
+
+use alloy::primitives::B256;
+use chrono::{DateTime, Utc};
+
+/// Timestamps of recently seen blocks, evicting the oldest entry once full. Keyed by block
+/// hash so that a block reorged away never lends its time to the one replacing it.
+#[derive(Debug)]
+pub struct BlockTimestampCache {
+    capacity: usize,
+    timestamps: HashMap<B256, DateTime<Utc>>,
+    order: VecDeque<B256>,
+}
+
+impl BlockTimestampCache {
+    pub fn new(capacity: usize) -> Self {
+        Self {
+            capacity,
+            timestamps: HashMap::with_capacity(capacity),
+            order: VecDeque::with_capacity(capacity),
+        }
+    }
+
+    pub fn get(&self, block_hash: &B256) -> Option<DateTime<Utc>> {
+        self.timestamps.get(block_hash).copied()
+    }
+
+    pub fn insert(&mut self, block_hash: B256, timestamp: DateTime<Utc>) {
+        if self.capacity == 0 || self.timestamps.insert(block_hash, timestamp).is_some() {
+            return;
+        }
+        self.order.push_back(block_hash);
+        if self.order.len() > self.capacity
+            && let Some(oldest) = self.order.pop_front()
+        {
+            self.timestamps.remove(&oldest);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn evicts_the_oldest_block_once_full() {
+        let mut cache = BlockTimestampCache::new(2);
+        let time = |seconds| DateTime::from_timestamp(seconds, 0).unwrap();
+        cache.insert(B256::repeat_byte(1), time(1));
+        cache.insert(B256::repeat_byte(2), time(2));
+        cache.insert(B256::repeat_byte(1), time(1));
+        cache.insert(B256::repeat_byte(3), time(3));
+
+        assert_eq!(cache.get(&B256::repeat_byte(1)), None);
+        assert_eq!(cache.get(&B256::repeat_byte(2)), Some(time(2)));
+        assert_eq!(cache.get(&B256::repeat_byte(3)), Some(time(3)));
+    }
+}
@@ -0,0 +1,68 @@
+use std::{collections::HashMap, error::Error, sync::Arc};
+
+use alloy::{
+    primitives::B256,
+    providers::DynProvider,
+    rpc::types::{Filter, Log},
+};
+use async_trait::async_trait;
+
+use crate::{
+    domain::entities::{ethereum_event::EthereumEventType, indexed_contract::IndexedContract},
+    infrastructure::services::{
+        abi_event_decoder::AbiEventDecoder, sol_event_decoders::built_in_decoder,
+    },
+};
+
+/// Turns the logs of an indexed contract into events.
+#[async_trait]
+pub trait EventDecoder: Send + Sync {
+    /// Signatures of the events to fetch, matched against the first topic of the logs.
+    fn event_signatures(&self) -> Vec<B256>;
+    /// Narrows down the filter on the contract address and event signatures, if needed.
+    fn restrict(&self, filter: Filter) -> Filter {
+        filter
+    }
+    /// Decodes one of the fetched logs, or returns `None` to skip it. The provider lets decoders
+    /// read what the log does not carry, such as contract state.
+    async fn decode(
+        &self,
+        log: &Log,
+        provider: &DynProvider,
+    ) -> Result<Option<EthereumEventType>, Box<dyn Error>>;
+}
+
+/// Decoder of each contract of a chain, by contract name.
+#[derive(Clone, Default)]
+pub struct EventDecoderRegistry {
+    decoders: HashMap<String, Arc<dyn EventDecoder>>,
+}
+
+impl EventDecoderRegistry {
+    /// Registers the decoder described by each contract: the one built from its ABI file when
+    /// it has one, the built-in decoder of the same name otherwise.
+    pub fn from_contracts(contracts: &[IndexedContract]) -> Result<Self, Box<dyn Error>> {
+        let mut registry = Self::default();
+        for contract in contracts {
+            let decoder: Arc<dyn EventDecoder> = match &contract.abi {
+                Some(abi) => Arc::new(AbiEventDecoder::from_file(
+                    &contract.name,
+                    abi,
+                    &contract.events,
+                )?),
+                None => built_in_decoder(contract)?,
+            };
+            registry.register(&contract.name, decoder);
+        }
+        Ok(registry)
+    }
+
+    /// Decodes the events of `contract` with `decoder`, replacing any decoder registered before.
+    pub fn register(&mut self, contract: &str, decoder: Arc<dyn EventDecoder>) {
+        self.decoders.insert(contract.to_string(), decoder);
+    }
+
+    pub fn get(&self, contract: &str) -> Option<Arc<dyn EventDecoder>> {
+        self.decoders.get(contract).cloned()
+    }
+}
@@ -0,0 +1,152 @@
+use std::{
+    collections::{HashMap, VecDeque},
+    sync::{Arc, Mutex},
+    time::{Duration, Instant},
+};
+
+use async_trait::async_trait;
+use chrono::Utc;
+use tokio::sync::OwnedMutexGuard;
+
+use crate::domain::{
+    entities::ingestion_status::{ContractActivity, IngestionError},
+    services::ingestion_control::IngestionControl,
+};
+
+/// Period the throughput of a contract is averaged over.
+const THROUGHPUT_WINDOW: Duration = Duration::from_secs(5 * 60);
+
+/// Ingestion control of a single indexer instance, kept in memory.
+#[derive(Default)]
+pub struct InMemoryIngestionControl {
+    chain_locks: Mutex<HashMap<u64, Arc<tokio::sync::Mutex<()>>>>,
+    chain_errors: Mutex<HashMap<u64, IngestionError>>,
+    contracts: Mutex<HashMap<(u64, String), ContractRecord>>,
+}
+
+#[derive(Default)]
+struct ContractRecord {
+    last_error: Option<IngestionError>,
+    /// Blocks and events ingested by each polling pass within the throughput window.
+    progress: VecDeque<(Instant, u64, usize)>,
+}
+
+impl ContractRecord {
+    fn prune(&mut self, now: Instant) {
+        while self
+            .progress
+            .front()
+            .is_some_and(|(at, _, _)| now.duration_since(*at) > THROUGHPUT_WINDOW)
+        {
+            self.progress.pop_front();
+        }
+    }
+}
+
+impl InMemoryIngestionControl {
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+
+#[async_trait]
+impl IngestionControl for InMemoryIngestionControl {
+    async fn lock_chain(&self, chain_id: u64) -> OwnedMutexGuard<()> {
+        let lock = self
+            .chain_locks
+            .lock()
+            .unwrap()
+            .entry(chain_id)
+            .or_default()
+            .clone();
+        lock.lock_owned().await
+    }
+
+    fn record_progress(&self, chain_id: u64, contract: &str, blocks: u64, events: usize) {
+        let now = Instant::now();
+        let mut contracts = self.contracts.lock().unwrap();
+        let record = contracts
+            .entry((chain_id, contract.to_string()))
+            .or_default();
+        record.prune(now);
+        record.progress.push_back((now, blocks, events));
+    }
+
+    fn record_error(&self, chain_id: u64, contract: Option<&str>, error: &str) {
+        let error = IngestionError {
+            message: error.to_string(),
+            at: Utc::now(),
+        };
+        match contract {
+            Some(contract) => {
+                self.contracts
+                    .lock()
+                    .unwrap()
+                    .entry((chain_id, contract.to_string()))
+                    .or_default()
+                    .last_error = Some(error);
+            }
+            None => {
+                self.chain_errors.lock().unwrap().insert(chain_id, error);
+            }
+        }
+    }
+
+    fn last_chain_error(&self, chain_id: u64) -> Option<IngestionError> {
+        self.chain_errors.lock().unwrap().get(&chain_id).cloned()
+    }
+
+    fn activity(&self, chain_id: u64, contract: &str) -> ContractActivity {
+        let mut contracts = self.contracts.lock().unwrap();
+        let Some(record) = contracts.get_mut(&(chain_id, contract.to_string())) else {
+            return ContractActivity::default();
+        };
+        record.prune(Instant::now());
+        let minutes = THROUGHPUT_WINDOW.as_secs_f64() / 60.0;
+        let (blocks, events) = record
+            .progress
+            .iter()
+            .fold((0, 0), |(blocks, events), (_, b, e)| {
+                (blocks + b, events + e)
+            });
+        ContractActivity {
+            last_error: record.last_error.clone(),
+            blocks_per_minute: blocks as f64 / minutes,
+            events_per_minute: events as f64 / minutes,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[tokio::test]
+    async fn averages_throughput_and_keeps_the_last_error_of_each_contract() {
+        let control = InMemoryIngestionControl::new();
+        control.record_progress(80002, "activity_token", 1000, 20);
+        control.record_progress(80002, "activity_token", 500, 5);
+        control.record_error(80002, Some("activity_token"), "HTTP error 503");
+        control.record_error(80002, None, "Block 100 not found");
+
+        let activity = control.activity(80002, "activity_token");
+        assert_eq!(activity.blocks_per_minute, 300.0);
+        assert_eq!(activity.events_per_minute, 5.0);
+        assert_eq!(activity.last_error.unwrap().message, "HTTP error 503");
+        assert_eq!(
+            control.last_chain_error(80002).unwrap().message,
+            "Block 100 not found"
+        );
+        assert_eq!(control.activity(80002, "eas"), ContractActivity::default());
+
+        // A second pass on the chain waits for the first one to end.
+        let pass = control.lock_chain(80002).await;
+        assert!(
+            tokio::time::timeout(Duration::from_millis(10), control.lock_chain(80002))
+                .await
+                .is_err()
+        );
+        drop(pass);
+        control.lock_chain(80002).await;
+    }
+}
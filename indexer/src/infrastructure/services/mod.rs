@@ -1 +1,10 @@
+pub mod abi_event_decoder;
 pub mod alloy_ethereum_event_polling_service;
+pub mod alloy_pubsub_ethereum_event_polling_service;
+pub mod block_timestamp_cache;
+pub mod event_decoder;
+pub mod in_memory_ingestion_control;
+pub mod prometheus_indexer_metrics;
+pub mod resilient_transport;
+pub mod sol_event_decoders;
+pub mod tokio_ethereum_event_broadcaster;
@@ -0,0 +1,158 @@
+use std::{error::Error, time::Duration};
+
+use prometheus::{
+    HistogramOpts, HistogramVec, IntCounterVec, IntGaugeVec, Opts, Registry, TextEncoder,
+};
+
+use crate::domain::{
+    entities::ethereum_event::EthereumEvent, services::indexer_metrics::IndexerMetrics,
+};
+
+/// RPC latency buckets in seconds, from a fast local node up to a request retried for a while.
+const RPC_LATENCY_BUCKETS: &[f64] = &[0.01, 0.025, 0.05, 0.1, 0.25, 0.5, 1.0, 2.5, 5.0, 10.0];
+
+/// Metrics kept in memory by this indexer instance and scraped from `/metrics`.
+pub struct PrometheusIndexerMetrics {
+    registry: Registry,
+    head_block: IntGaugeVec,
+    last_indexed_block: IntGaugeVec,
+    lag_blocks: IntGaugeVec,
+    events_ingested: IntCounterVec,
+    reorgs: IntCounterVec,
+    rpc_request_duration: HistogramVec,
+    rpc_errors: IntCounterVec,
+}
+
+impl PrometheusIndexerMetrics {
+    pub fn new() -> Result<Self, prometheus::Error> {
+        let registry = Registry::new_custom(Some("indexer".to_string()), None)?;
+        let head_block = IntGaugeVec::new(
+            Opts::new("head_block", "Latest block of the chain seen while polling"),
+            &["chain_id"],
+        )?;
+        let last_indexed_block = IntGaugeVec::new(
+            Opts::new("last_indexed_block", "Last block ingested for the contract"),
+            &["chain_id", "contract"],
+        )?;
+        let lag_blocks = IntGaugeVec::new(
+            Opts::new(
+                "lag_blocks",
+                "Blocks between the chain head and the last block ingested for the contract",
+            ),
+            &["chain_id", "contract"],
+        )?;
+        let events_ingested = IntCounterVec::new(
+            Opts::new("events_ingested_total", "Events stored, by type"),
+            &["chain_id", "event_type"],
+        )?;
+        let reorgs = IntCounterVec::new(
+            Opts::new("reorgs_total", "Chain reorganizations rolled back"),
+            &["chain_id"],
+        )?;
+        let rpc_request_duration = HistogramVec::new(
+            HistogramOpts::new(
+                "rpc_request_duration_seconds",
+                "Latency of the requests sent to RPC endpoints",
+            )
+            .buckets(RPC_LATENCY_BUCKETS.to_vec()),
+            &["chain_id", "method"],
+        )?;
+        let rpc_errors = IntCounterVec::new(
+            Opts::new("rpc_errors_total", "Requests to RPC endpoints that failed"),
+            &["chain_id", "method"],
+        )?;
+
+        registry.register(Box::new(head_block.clone()))?;
+        registry.register(Box::new(last_indexed_block.clone()))?;
+        registry.register(Box::new(lag_blocks.clone()))?;
+        registry.register(Box::new(events_ingested.clone()))?;
+        registry.register(Box::new(reorgs.clone()))?;
+        registry.register(Box::new(rpc_request_duration.clone()))?;
+        registry.register(Box::new(rpc_errors.clone()))?;
+
+        Ok(Self {
+            registry,
+            head_block,
+            last_indexed_block,
+            lag_blocks,
+            events_ingested,
+            reorgs,
+            rpc_request_duration,
+            rpc_errors,
+        })
+    }
+}
+
+impl IndexerMetrics for PrometheusIndexerMetrics {
+    fn record_contract_progress(
+        &self,
+        chain_id: u64,
+        contract: &str,
+        last_indexed_block: u64,
+        head_block: u64,
+    ) {
+        let chain_id = chain_id.to_string();
+        self.head_block
+            .with_label_values(&[&chain_id])
+            .set(head_block as i64);
+        self.last_indexed_block
+            .with_label_values(&[&chain_id, contract])
+            .set(last_indexed_block as i64);
+        self.lag_blocks
+            .with_label_values(&[&chain_id, contract])
+            .set(head_block.saturating_sub(last_indexed_block) as i64);
+    }
+
+    fn record_events_ingested(&self, chain_id: u64, ethereum_events: &[EthereumEvent]) {
+        let chain_id = chain_id.to_string();
+        for event in ethereum_events {
+            self.events_ingested
+                .with_label_values(&[&chain_id, event.event_type.name()])
+                .inc();
+        }
+    }
+
+    fn record_reorg(&self, chain_id: u64) {
+        self.reorgs
+            .with_label_values(&[&chain_id.to_string()])
+            .inc();
+    }
+
+    fn record_rpc_request(&self, chain_id: u64, method: &str, latency: Duration, failed: bool) {
+        let chain_id = chain_id.to_string();
+        self.rpc_request_duration
+            .with_label_values(&[&chain_id, method])
+            .observe(latency.as_secs_f64());
+        if failed {
+            self.rpc_errors
+                .with_label_values(&[&chain_id, method])
+                .inc();
+        }
+    }
+
+    fn export(&self) -> Result<String, Box<dyn Error>> {
+        Ok(TextEncoder::new().encode_to_string(&self.registry.gather())?)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn exports_the_lag_of_each_contract() {
+        let metrics = PrometheusIndexerMetrics::new().unwrap();
+        metrics.record_contract_progress(80002, "activity_token", 90, 100);
+        metrics.record_rpc_request(80002, "eth_getLogs", Duration::from_millis(30), true);
+
+        let exported = metrics.export().unwrap();
+        assert!(
+            exported
+                .contains(r#"indexer_lag_blocks{chain_id="80002",contract="activity_token"} 10"#)
+        );
+        assert!(
+            exported
+                .contains(r#"indexer_rpc_errors_total{chain_id="80002",method="eth_getLogs"} 1"#)
+        );
+    }
+}
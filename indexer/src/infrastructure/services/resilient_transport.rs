@@ -0,0 +1,381 @@
+use std::{
+    sync::{Arc, Mutex},
+    task::{Context, Poll},
+    time::Duration,
+};
+
+use alloy::{
+    rpc::json_rpc::{RequestPacket, ResponsePacket},
+    transports::{
+        BoxTransport, RpcError, TransportError, TransportFut, TransportResult,
+        layers::{RateLimitRetryPolicy, RetryPolicy},
+    },
+};
+use tokio::time::{Instant, sleep, sleep_until};
+use tower::Service;
+
+use crate::{
+    domain::services::indexer_metrics::IndexerMetrics,
+    infrastructure::services::alloy_ethereum_event_polling_service::is_too_many_logs_error,
+};
+
+/// How [`ResilientTransport`] retries, paces and fails over requests.
+#[derive(Debug, Clone, PartialEq)]
+pub struct RpcResilienceConfig {
+    /// Rounds over the endpoints after the first one before a request fails.
+    pub max_retries: u32,
+    /// Delay before the first retry, doubled on each further one.
+    pub initial_backoff: Duration,
+    pub max_backoff: Duration,
+    /// Requests sent per second over all endpoints of a chain; unlimited when unset.
+    pub requests_per_second: Option<u32>,
+    /// How long an endpoint that failed is only tried after the healthy ones.
+    pub unhealthy_cooldown: Duration,
+}
+
+impl Default for RpcResilienceConfig {
+    fn default() -> Self {
+        Self {
+            max_retries: 5,
+            initial_backoff: Duration::from_millis(250),
+            max_backoff: Duration::from_secs(10),
+            requests_per_second: None,
+            unhealthy_cooldown: Duration::from_secs(30),
+        }
+    }
+}
+
+/// Transport sending each request to the first healthy endpoint, in order of preference. Transient
+/// failures (connection errors, rate limits, unavailable nodes) mark the endpoint unhealthy and
+/// move on to the next one; once every endpoint failed, the round is retried with exponential
+/// backoff. Any other error, such as a log range yielding too many results, is returned as is.
+#[derive(Clone)]
+pub struct ResilientTransport {
+    inner: Arc<Inner>,
+}
+
+struct Inner {
+    endpoints: Vec<Endpoint>,
+    config: RpcResilienceConfig,
+    chain_id: u64,
+    indexer_metrics: Arc<dyn IndexerMetrics>,
+    /// Earliest time the next request may be sent to stay within the budget.
+    next_slot: Mutex<Instant>,
+}
+
+struct Endpoint {
+    url: String,
+    transport: BoxTransport,
+    health: Mutex<EndpointHealth>,
+}
+
+#[derive(Default)]
+struct EndpointHealth {
+    consecutive_failures: u32,
+    unhealthy_until: Option<Instant>,
+}
+
+impl ResilientTransport {
+    /// Takes the endpoints in order of preference, each with the URL it is reported under.
+    /// Every request sent to them is recorded in the metrics of the chain they serve.
+    pub fn new(
+        endpoints: Vec<(String, BoxTransport)>,
+        config: RpcResilienceConfig,
+        chain_id: u64,
+        indexer_metrics: Arc<dyn IndexerMetrics>,
+    ) -> Self {
+        Self {
+            inner: Arc::new(Inner {
+                endpoints: endpoints
+                    .into_iter()
+                    .map(|(url, transport)| Endpoint {
+                        url,
+                        transport,
+                        health: Mutex::new(EndpointHealth::default()),
+                    })
+                    .collect(),
+                config,
+                chain_id,
+                indexer_metrics,
+                next_slot: Mutex::new(Instant::now()),
+            }),
+        }
+    }
+
+    async fn send(self, request: RequestPacket) -> TransportResult<ResponsePacket> {
+        let method = match &request {
+            RequestPacket::Single(request) => request.method().to_string(),
+            RequestPacket::Batch(_) => "batch".to_string(),
+        };
+        let mut retries = 0;
+        loop {
+            let mut last_error = None;
+            for endpoint in self.endpoints_by_preference() {
+                self.throttle().await;
+                let sent_at = Instant::now();
+                let outcome = endpoint.transport.clone().call(request.clone()).await;
+                self.inner.indexer_metrics.record_rpc_request(
+                    self.inner.chain_id,
+                    &method,
+                    sent_at.elapsed(),
+                    !outcome.as_ref().is_ok_and(ResponsePacket::is_success),
+                );
+                let error = match outcome {
+                    Ok(response) => match response.as_error() {
+                        Some(payload) if is_transient(&RpcError::ErrorResp(payload.clone())) => {
+                            RpcError::ErrorResp(payload.clone())
+                        }
+                        _ => {
+                            endpoint.record_success();
+                            return Ok(response);
+                        }
+                    },
+                    Err(error) if is_transient(&error) => error,
+                    Err(error) => return Err(error),
+                };
+                endpoint.record_failure(&error, self.inner.config.unhealthy_cooldown);
+                last_error = Some(error);
+            }
+
+            let Some(error) = last_error else {
+                return Err(RpcError::local_usage_str("No RPC endpoint configured"));
+            };
+            if retries >= self.inner.config.max_retries {
+                return Err(error);
+            }
+            let backoff = backoff(&self.inner.config, retries, &error);
+            tracing::debug!(
+                "Every RPC endpoint failed, retrying in {:?}: {}",
+                backoff,
+                error
+            );
+            sleep(backoff).await;
+            retries += 1;
+        }
+    }
+
+    /// Healthy endpoints first, then those cooling down, each group in configured order.
+    fn endpoints_by_preference(&self) -> Vec<&Endpoint> {
+        let now = Instant::now();
+        let (mut healthy, unhealthy): (Vec<_>, Vec<_>) = self
+            .inner
+            .endpoints
+            .iter()
+            .partition(|endpoint| endpoint.is_healthy(now));
+        healthy.extend(unhealthy);
+        healthy
+    }
+
+    /// Waits for the next free slot of the request-per-second budget.
+    async fn throttle(&self) {
+        let Some(requests_per_second) = self.inner.config.requests_per_second else {
+            return;
+        };
+        let slot = {
+            let mut next_slot = self.inner.next_slot.lock().unwrap();
+            let slot = (*next_slot).max(Instant::now());
+            *next_slot = slot + Duration::from_secs(1) / requests_per_second;
+            slot
+        };
+        sleep_until(slot).await;
+    }
+}
+
+impl Endpoint {
+    fn is_healthy(&self, now: Instant) -> bool {
+        let health = self.health.lock().unwrap();
+        health.unhealthy_until.is_none_or(|until| until <= now)
+    }
+
+    fn record_success(&self) {
+        let mut health = self.health.lock().unwrap();
+        if health.consecutive_failures > 0 {
+            tracing::info!(
+                "RPC endpoint {} recovered after {} failures",
+                self.url,
+                health.consecutive_failures
+            );
+        }
+        *health = EndpointHealth::default();
+    }
+
+    fn record_failure(&self, error: &TransportError, cooldown: Duration) {
+        let mut health = self.health.lock().unwrap();
+        health.consecutive_failures += 1;
+        health.unhealthy_until = Some(Instant::now() + cooldown);
+        tracing::warn!(
+            "RPC endpoint {} failed ({} in a row): {}",
+            self.url,
+            health.consecutive_failures,
+            error
+        );
+    }
+}
+
+impl Service<RequestPacket> for ResilientTransport {
+    type Response = ResponsePacket;
+    type Error = TransportError;
+    type Future = TransportFut<'static>;
+
+    fn poll_ready(&mut self, _cx: &mut Context<'_>) -> Poll<Result<(), Self::Error>> {
+        Poll::Ready(Ok(()))
+    }
+
+    fn call(&mut self, request: RequestPacket) -> Self::Future {
+        Box::pin(self.clone().send(request))
+    }
+}
+
+/// Whether another endpoint, or the same one a bit later, may answer differently: the node could
+/// not be reached or is overloaded. A log range that is too large is not, even when providers
+/// report it with a rate limit code.
+fn is_transient(error: &TransportError) -> bool {
+    match error {
+        RpcError::Transport(_) | RpcError::NullResp => true,
+        RpcError::ErrorResp(payload) => {
+            payload.is_retry_err() && !is_too_many_logs_error(&payload.message)
+        }
+        _ => RateLimitRetryPolicy::default().should_retry(error),
+    }
+}
+
+/// Delay before the given retry, as long as the provider asks or doubling from the initial
+/// backoff, up to the maximum either way.
+fn backoff(config: &RpcResilienceConfig, retry: u32, error: &TransportError) -> Duration {
+    RateLimitRetryPolicy::default()
+        .backoff_hint(error)
+        .unwrap_or_else(|| {
+            config
+                .initial_backoff
+                .saturating_mul(2u32.saturating_pow(retry))
+        })
+        .min(config.max_backoff)
+}
+
+#[cfg(test)]
+mod tests {
+    use alloy::{
+        primitives::U64,
+        providers::{Provider, ProviderBuilder},
+        rpc::{client::RpcClient, json_rpc::ErrorPayload},
+        transports::mock::{Asserter, MockTransport},
+    };
+
+    use super::*;
+    use crate::infrastructure::services::prometheus_indexer_metrics::PrometheusIndexerMetrics;
+
+    fn config() -> RpcResilienceConfig {
+        RpcResilienceConfig {
+            max_retries: 1,
+            initial_backoff: Duration::from_millis(1),
+            max_backoff: Duration::from_millis(1),
+            requests_per_second: None,
+            unhealthy_cooldown: Duration::from_secs(60),
+        }
+    }
+
+    /// An endpoint answering with the queued responses, and failing to connect once out of them.
+    fn endpoint(url: &str) -> (Asserter, (String, BoxTransport)) {
+        let asserter = Asserter::new();
+        let transport = BoxTransport::new(MockTransport::new(asserter.clone()));
+        (asserter, (url.to_string(), transport))
+    }
+
+    #[tokio::test]
+    async fn fails_over_to_the_next_endpoint_and_skips_it_while_unhealthy() {
+        let (_, primary) = endpoint("primary");
+        let (fallback_asserter, fallback) = endpoint("fallback");
+        fallback_asserter.push_success(&U64::from(1));
+        fallback_asserter.push_success(&U64::from(2));
+        let provider = ProviderBuilder::new().connect_client(RpcClient::new(
+            ResilientTransport::new(
+                vec![primary, fallback],
+                config(),
+                1,
+                Arc::new(PrometheusIndexerMetrics::new().unwrap()),
+            ),
+            true,
+        ));
+
+        assert_eq!(provider.get_block_number().await.unwrap(), 1);
+        // The primary endpoint is cooling down, so it is not tried before the fallback.
+        assert_eq!(provider.get_block_number().await.unwrap(), 2);
+        assert!(fallback_asserter.read_q().is_empty());
+    }
+
+    #[tokio::test]
+    async fn returns_errors_that_no_retry_would_fix() {
+        let (primary_asserter, primary) = endpoint("primary");
+        let (fallback_asserter, fallback) = endpoint("fallback");
+        // Infura reports too large log ranges with its rate limit code.
+        primary_asserter.push_failure(ErrorPayload {
+            code: -32005,
+            message: "query returned more than 10000 results".into(),
+            data: None,
+        });
+        fallback_asserter.push_success(&U64::from(1));
+        let provider = ProviderBuilder::new().connect_client(RpcClient::new(
+            ResilientTransport::new(
+                vec![primary, fallback],
+                config(),
+                1,
+                Arc::new(PrometheusIndexerMetrics::new().unwrap()),
+            ),
+            true,
+        ));
+
+        let err = provider.get_block_number().await.unwrap_err();
+        assert!(err.to_string().contains("10000 results"));
+        assert_eq!(fallback_asserter.read_q().len(), 1);
+    }
+
+    #[tokio::test]
+    async fn fails_over_when_a_quota_is_exceeded() {
+        let (primary_asserter, primary) = endpoint("primary");
+        let (fallback_asserter, fallback) = endpoint("fallback");
+        // Worded like some log range errors, but a smaller range would not help.
+        primary_asserter.push_failure(ErrorPayload {
+            code: -32005,
+            message: "daily request limit exceeded".into(),
+            data: None,
+        });
+        fallback_asserter.push_success(&U64::from(1));
+        let provider = ProviderBuilder::new().connect_client(RpcClient::new(
+            ResilientTransport::new(
+                vec![primary, fallback],
+                config(),
+                1,
+                Arc::new(PrometheusIndexerMetrics::new().unwrap()),
+            ),
+            true,
+        ));
+
+        assert_eq!(provider.get_block_number().await.unwrap(), 1);
+    }
+
+    #[test]
+    fn waits_as_long_as_the_provider_asks_up_to_the_maximum() {
+        let rate_limited = |backoff_seconds: u64| {
+            RpcError::ErrorResp(ErrorPayload {
+                code: -32005,
+                message: "daily request limit exceeded".into(),
+                data: Some(
+                    serde_json::value::to_raw_value(
+                        &serde_json::json!({ "rate": { "backoff_seconds": backoff_seconds } }),
+                    )
+                    .unwrap(),
+                ),
+            })
+        };
+        let config = RpcResilienceConfig::default();
+
+        assert_eq!(
+            backoff(&config, 0, &rate_limited(2)),
+            Duration::from_secs(2)
+        );
+        assert_eq!(
+            backoff(&config, 0, &rate_limited(86_400)),
+            config.max_backoff
+        );
+    }
+}
@@ -0,0 +1,194 @@
+use std::{error::Error, sync::Arc};
+
+use alloy::{
+    primitives::{Address, B256, Bytes},
+    providers::DynProvider,
+    rpc::types::{Filter, Log},
+    sol,
+    sol_types::{SolEvent, SolValue},
+};
+use async_trait::async_trait;
+
+use crate::{
+    domain::entities::{ethereum_event::EthereumEventType, indexed_contract::IndexedContract},
+    infrastructure::services::event_decoder::EventDecoder,
+};
+
+pub const ACTIVITY_TOKEN_CONTRACT: &str = "activity_token";
+pub const BADGE_RANKING_CONTRACT: &str = "badge_ranking";
+pub const BADGE_REGISTRY_CONTRACT: &str = "badge_registry";
+pub const EAS_CONTRACT: &str = "eas";
+
+/// Contract names decoded without an ABI file, into the events the projections are built from.
+pub const BUILT_IN_CONTRACTS: &[&str] = &[
+    ACTIVITY_TOKEN_CONTRACT,
+    BADGE_RANKING_CONTRACT,
+    BADGE_REGISTRY_CONTRACT,
+    EAS_CONTRACT,
+];
+
+sol!(ITokenActivity, "contracts/activity_token_abi.json");
+sol!(IBadgeRanking, "contracts/badge_ranking_abi.json");
+sol!(IBadgeRegistry, "contracts/badge_registry_abi.json");
+sol!(
+    #[sol(rpc)]
+    IEAS,
+    "contracts/eas_abi.json"
+);
+
+/// Returns the built-in decoder of the contract, found by its name.
+pub fn built_in_decoder(
+    contract: &IndexedContract,
+) -> Result<Arc<dyn EventDecoder>, Box<dyn Error>> {
+    Ok(match contract.name.as_str() {
+        ACTIVITY_TOKEN_CONTRACT => Arc::new(ActivityTokenDecoder),
+        BADGE_RANKING_CONTRACT => Arc::new(BadgeRankingDecoder),
+        BADGE_REGISTRY_CONTRACT => Arc::new(BadgeRegistryDecoder),
+        EAS_CONTRACT => Arc::new(EasDecoder {
+            schema_id: contract
+                .schema_id
+                .ok_or("Missing schema id for the EAS contract")?,
+        }),
+        name => return Err(format!("Unknown contract: {name}").into()),
+    })
+}
+
+/// Transfers of the activity token, mints told apart from other transfers.
+pub struct ActivityTokenDecoder;
+
+#[async_trait]
+impl EventDecoder for ActivityTokenDecoder {
+    fn event_signatures(&self) -> Vec<B256> {
+        vec![ITokenActivity::Transfer::SIGNATURE_HASH]
+    }
+
+    async fn decode(
+        &self,
+        log: &Log,
+        _provider: &DynProvider,
+    ) -> Result<Option<EthereumEventType>, Box<dyn Error>> {
+        let transfer = ITokenActivity::Transfer::decode_log_data(log.data())?;
+        Ok(Some(if transfer.from == Address::ZERO {
+            EthereumEventType::ActivityTokenMinted {
+                recipient: transfer.to,
+                amount: transfer.value,
+            }
+        } else {
+            EthereumEventType::ActivityTokenTransferred {
+                from: transfer.from,
+                to: transfer.to,
+                amount: transfer.value,
+            }
+        }))
+    }
+}
+
+pub struct BadgeRegistryDecoder;
+
+#[async_trait]
+impl EventDecoder for BadgeRegistryDecoder {
+    fn event_signatures(&self) -> Vec<B256> {
+        vec![IBadgeRegistry::BadgeCreated::SIGNATURE_HASH]
+    }
+
+    async fn decode(
+        &self,
+        log: &Log,
+        _provider: &DynProvider,
+    ) -> Result<Option<EthereumEventType>, Box<dyn Error>> {
+        let badge_created = IBadgeRegistry::BadgeCreated::decode_log_data(log.data())?;
+        Ok(Some(EthereumEventType::BadgeCreated {
+            name: bytes32_to_string(&badge_created.name),
+            description: String::from_utf8_lossy(&badge_created.description).into_owned(),
+            creator: badge_created.creator,
+        }))
+    }
+}
+
+pub struct BadgeRankingDecoder;
+
+#[async_trait]
+impl EventDecoder for BadgeRankingDecoder {
+    fn event_signatures(&self) -> Vec<B256> {
+        vec![IBadgeRanking::BadgeUpvoted::SIGNATURE_HASH]
+    }
+
+    async fn decode(
+        &self,
+        log: &Log,
+        _provider: &DynProvider,
+    ) -> Result<Option<EthereumEventType>, Box<dyn Error>> {
+        let badge_upvoted = IBadgeRanking::BadgeUpvoted::decode_log_data(log.data())?;
+        Ok(Some(EthereumEventType::BadgeUpvoted {
+            badge_name: bytes32_to_string(&badge_upvoted.badgeName),
+            voter: badge_upvoted.voter,
+        }))
+    }
+}
+
+/// Attestations of a single EAS schema, created or revoked.
+pub struct EasDecoder {
+    schema_id: B256,
+}
+
+#[async_trait]
+impl EventDecoder for EasDecoder {
+    fn event_signatures(&self) -> Vec<B256> {
+        vec![
+            IEAS::Attested::SIGNATURE_HASH,
+            IEAS::Revoked::SIGNATURE_HASH,
+        ]
+    }
+
+    fn restrict(&self, filter: Filter) -> Filter {
+        // The schema UID is the third indexed topic of both events.
+        filter.topic3(self.schema_id)
+    }
+
+    async fn decode(
+        &self,
+        log: &Log,
+        provider: &DynProvider,
+    ) -> Result<Option<EthereumEventType>, Box<dyn Error>> {
+        let eas = IEAS::new(log.address(), provider);
+
+        // The events only carry the uid, the payload and times live in the attestation itself.
+        if log.topic0() == Some(&IEAS::Attested::SIGNATURE_HASH) {
+            let attested = IEAS::Attested::decode_log_data(log.data())?;
+            let attestation = eas.getAttestation(attested.uid).call().await?;
+            let Ok((badge_name, justification)) =
+                <(B256, Bytes)>::abi_decode_params(&attestation.data)
+            else {
+                tracing::warn!(
+                    "Skipping attestation {} with undecodable data",
+                    attested.uid
+                );
+                return Ok(None);
+            };
+            Ok(Some(EthereumEventType::AttestationCreated {
+                uid: attested.uid,
+                attester: attested.attester,
+                recipient: attested.recipient,
+                badge_name: bytes32_to_string(&badge_name),
+                justification: String::from_utf8_lossy(&justification).into_owned(),
+                time: attestation.time,
+            }))
+        } else {
+            let revoked = IEAS::Revoked::decode_log_data(log.data())?;
+            let attestation = eas.getAttestation(revoked.uid).call().await?;
+            Ok(Some(EthereumEventType::AttestationRevoked {
+                uid: revoked.uid,
+                attester: revoked.attester,
+                recipient: revoked.recipient,
+                revocation_time: attestation.revocationTime,
+            }))
+        }
+    }
+}
+
+/// Badge names are right-padded UTF-8 strings stored in a `bytes32`.
+fn bytes32_to_string(value: &B256) -> String {
+    String::from_utf8_lossy(value.as_slice())
+        .trim_end_matches('\0')
+        .to_string()
+}
@@ -0,0 +1,31 @@
+use tokio::sync::broadcast;
+
+use crate::domain::{
+    entities::ethereum_event::EthereumEvent,
+    services::ethereum_event_broadcaster::EthereumEventBroadcaster,
+};
+
+/// In-process broadcaster, so subscribers only see events committed by this indexer instance.
+pub struct TokioEthereumEventBroadcaster {
+    sender: broadcast::Sender<EthereumEvent>,
+}
+
+impl TokioEthereumEventBroadcaster {
+    pub fn new(capacity: usize) -> Self {
+        let (sender, _) = broadcast::channel(capacity);
+        Self { sender }
+    }
+}
+
+impl EthereumEventBroadcaster for TokioEthereumEventBroadcaster {
+    fn publish(&self, ethereum_events: &[EthereumEvent]) {
+        for event in ethereum_events {
+            // Sending only fails when nobody is subscribed.
+            let _ = self.sender.send(event.clone());
+        }
+    }
+
+    fn subscribe(&self) -> broadcast::Receiver<EthereumEvent> {
+        self.sender.subscribe()
+    }
+}
@@ -1,25 +1,35 @@
 pub mod application;
+pub mod cli;
+pub mod config;
 pub mod domain;
 pub mod infrastructure;
 pub mod presentation;
 
-use presentation::api::create_app;
-use std::{env, net::SocketAddr};
+use application::commands::{backfill::backfill, poll_loop::poll_loop};
+use clap::Parser;
+use cli::{Cli, Command};
+use config::IndexerConfig;
+use domain::services::ethereum_event_polling_service::EthereumEventPollingService;
+use presentation::api::{create_app, create_state};
+use std::{env, net::SocketAddr, time::Duration};
 use tracing_subscriber::{layer::SubscriberExt, util::SubscriberInitExt};
 
 #[tokio::main]
 async fn main() -> anyhow::Result<()> {
+    let cli = Cli::parse();
     dotenvy::dotenv().ok();
     let database_url = env::var("DATABASE_URL").expect("DATABASE_URL must be set");
 
     tracing_subscriber::registry()
         .with(
             tracing_subscriber::EnvFilter::try_from_default_env()
-                .unwrap_or_else(|_| "guild_backend=debug,tower_http=debug".into()),
+                .unwrap_or_else(|_| "guild_indexer=debug,tower_http=debug".into()),
         )
         .with(tracing_subscriber::fmt::layer())
         .init();
 
+    let config = IndexerConfig::from_env()?;
+
     let pool = sqlx::PgPool::connect(&database_url)
         .await
         .unwrap_or_else(|_| {
@@ -29,7 +39,63 @@ async fn main() -> anyhow::Result<()> {
 
     sqlx::migrate!("./migrations").run(&pool).await?;
 
-    let app = create_app(pool).await;
+    let state = create_state(pool, &config)?;
+
+    if let Some(Command::Backfill {
+        from,
+        to,
+        chain_id,
+        contracts,
+    }) = cli.command
+    {
+        let ethereum_event_polling_service =
+            match (chain_id, &state.ethereum_event_polling_services[..]) {
+                (None, [service]) => service.clone(),
+                (None, _) => anyhow::bail!("Several chains are configured, pick one with --chain"),
+                (Some(chain_id), services) => services
+                    .iter()
+                    .find(|service| service.chain_id() == chain_id)
+                    .cloned()
+                    .ok_or_else(|| anyhow::anyhow!("Chain {chain_id} is not configured"))?,
+            };
+        // Only the backfilled chain has to be reachable.
+        check_chain_id(ethereum_event_polling_service.as_ref()).await?;
+        let events_ingested = backfill(
+            ethereum_event_polling_service,
+            state.ethereum_event_repository.clone(),
+            state.block_cursor_repository.clone(),
+            state.block_header_repository.clone(),
+            &contracts,
+            from,
+            to,
+            config.batch_size,
+        )
+        .await
+        .map_err(|e| anyhow::anyhow!("Backfill failed: {e}"))?;
+        tracing::info!("Backfill complete, {} events ingested", events_ingested);
+        return Ok(());
+    }
+
+    for ethereum_event_polling_service in &state.ethereum_event_polling_services {
+        check_chain_id(ethereum_event_polling_service.as_ref()).await?;
+    }
+
+    // Each chain is polled by its own loop, so a slow or failing chain does not hold back the others.
+    for ethereum_event_polling_service in &state.ethereum_event_polling_services {
+        tokio::spawn(poll_loop(
+            ethereum_event_polling_service.clone(),
+            state.ethereum_event_repository.clone(),
+            state.block_cursor_repository.clone(),
+            state.block_header_repository.clone(),
+            state.ethereum_event_broadcaster.clone(),
+            state.indexer_metrics.clone(),
+            state.ingestion_control.clone(),
+            config.batch_size,
+            Duration::from_millis(config.poll_interval_ms),
+        ));
+    }
+
+    let app = create_app(state).await;
 
     let port = env::var("PORT").unwrap_or_else(|_| "3002".to_string());
     let addr = SocketAddr::from(([0, 0, 0, 0], port.parse::<u16>().unwrap()));
@@ -40,3 +106,22 @@ async fn main() -> anyhow::Result<()> {
 
     Ok(())
 }
+
+/// Fails unless the service's RPC endpoint serves the chain it is configured for.
+async fn check_chain_id(
+    ethereum_event_polling_service: &dyn EthereumEventPollingService,
+) -> anyhow::Result<()> {
+    let expected = ethereum_event_polling_service.chain_id();
+    let chain_id = ethereum_event_polling_service
+        .node_chain_id()
+        .await
+        .map_err(|e| anyhow::anyhow!("Could not reach RPC endpoint of chain {expected}: {e}"))?;
+    if chain_id != expected {
+        anyhow::bail!(
+            "RPC endpoint serves chain {}, expected chain id {}",
+            chain_id,
+            expected
+        );
+    }
+    Ok(())
+}
@@ -1,33 +1,168 @@
+use axum::Router;
 use axum::extract::DefaultBodyLimit;
 use axum::http::Method;
+use axum::middleware::from_fn_with_state;
 use axum::routing::{get, post};
-use axum::Router;
 use std::sync::Arc;
 use tower::ServiceBuilder;
 use tower_http::cors::{Any, CorsLayer};
 use tower_http::trace::TraceLayer;
 
+use super::handlers::events_websocket_handler::events_websocket_handler;
+use super::handlers::get_attestation_handler::get_attestation_handler;
+use super::handlers::get_badge_handler::get_badge_handler;
+use super::handlers::get_badge_ranking_handler::get_badge_ranking_handler;
+use super::handlers::get_ingestion_status_handler::get_ingestion_status_handler;
+use super::handlers::get_top_token_holders_handler::get_top_token_holders_handler;
+use super::handlers::health_handler::health_handler;
+use super::handlers::list_attestations_handler::list_attestations_handler;
+use super::handlers::list_badge_upvotes_by_voter_handler::list_badge_upvotes_by_voter_handler;
+use super::handlers::list_badges_by_creator_handler::list_badges_by_creator_handler;
+use super::handlers::list_badges_handler::list_badges_handler;
 use super::handlers::list_events_handler::list_events_handler;
+use super::handlers::list_token_balances_by_address_handler::list_token_balances_by_address_handler;
+use super::handlers::metrics_handler::metrics_handler;
+use super::handlers::pause_contract_handler::pause_contract_handler;
 use super::handlers::poll_handler::poll_handler;
+use super::handlers::ready_handler::ready_handler;
+use super::handlers::resume_contract_handler::resume_contract_handler;
+use super::handlers::rewind_contract_handler::rewind_contract_handler;
+use super::handlers::stream_events_handler::stream_events_handler;
+use super::middlewares::admin_auth_layer;
+use crate::config::IndexerConfig;
+use crate::domain::repositories::attestation_repository::AttestationRepository;
+use crate::domain::repositories::badge_repository::BadgeRepository;
+use crate::domain::repositories::badge_upvote_repository::BadgeUpvoteRepository;
+use crate::domain::repositories::block_cursor_repository::BlockCursorRepository;
+use crate::domain::repositories::block_header_repository::BlockHeaderRepository;
 use crate::domain::repositories::ethereum_event_repository::EthereumEventRepository;
+use crate::domain::repositories::token_balance_repository::TokenBalanceRepository;
+use crate::domain::services::ethereum_event_broadcaster::EthereumEventBroadcaster;
 use crate::domain::services::ethereum_event_polling_service::EthereumEventPollingService;
+use crate::domain::services::indexer_metrics::IndexerMetrics;
+use crate::domain::services::ingestion_control::IngestionControl;
+use crate::infrastructure::repositories::postgres_attestation_repository::PostgresAttestationRepository;
+use crate::infrastructure::repositories::postgres_badge_repository::PostgresBadgeRepository;
+use crate::infrastructure::repositories::postgres_badge_upvote_repository::PostgresBadgeUpvoteRepository;
+use crate::infrastructure::repositories::postgres_block_cursor_repository::PostgresBlockCursorRepository;
+use crate::infrastructure::repositories::postgres_block_header_repository::PostgresBlockHeaderRepository;
 use crate::infrastructure::repositories::postgres_ethereum_event_repository::PostgresEthereumEventRepository;
+use crate::infrastructure::repositories::postgres_token_balance_repository::PostgresTokenBalanceRepository;
 use crate::infrastructure::services::alloy_ethereum_event_polling_service::AlloyEthereumEventPollingService;
+use crate::infrastructure::services::alloy_pubsub_ethereum_event_polling_service::AlloyPubsubEthereumEventPollingService;
+use crate::infrastructure::services::event_decoder::EventDecoderRegistry;
+use crate::infrastructure::services::in_memory_ingestion_control::InMemoryIngestionControl;
+use crate::infrastructure::services::prometheus_indexer_metrics::PrometheusIndexerMetrics;
+use crate::infrastructure::services::tokio_ethereum_event_broadcaster::TokioEthereumEventBroadcaster;
+
+/// Events buffered per live subscriber before it is considered lagging and disconnected.
+const EVENT_BROADCAST_CAPACITY: usize = 1024;
+
+pub fn create_state(pool: sqlx::PgPool, config: &IndexerConfig) -> anyhow::Result<AppState> {
+    let postgres_ethereum_event_repository = PostgresEthereumEventRepository::new(pool.clone());
+    let postgres_block_cursor_repository = PostgresBlockCursorRepository::new(pool.clone());
+    let postgres_block_header_repository = PostgresBlockHeaderRepository::new(pool.clone());
+    let postgres_badge_repository = PostgresBadgeRepository::new(pool.clone());
+    let postgres_badge_upvote_repository = PostgresBadgeUpvoteRepository::new(pool.clone());
+    let postgres_attestation_repository = PostgresAttestationRepository::new(pool.clone());
+    let postgres_token_balance_repository = PostgresTokenBalanceRepository::new(pool);
 
-pub async fn create_app(pool: sqlx::PgPool) -> Router {
-    let postgres_ethereum_event_repository = PostgresEthereumEventRepository::new(pool);
-    let alloy_ethereum_event_polling_service = AlloyEthereumEventPollingService::new();
+    let indexer_metrics: Arc<dyn IndexerMetrics> = Arc::new(PrometheusIndexerMetrics::new()?);
 
-    let state: AppState = AppState {
+    let mut ethereum_event_polling_services: Vec<Arc<dyn EthereumEventPollingService>> = Vec::new();
+    for chain in &config.chains {
+        let decoders = EventDecoderRegistry::from_contracts(&chain.contracts).map_err(|e| {
+            anyhow::anyhow!("Could not create event decoders for {}: {e}", chain.name)
+        })?;
+        let alloy_ethereum_event_polling_service = AlloyEthereumEventPollingService::new(
+            &chain.rpc_urls,
+            chain.chain_id,
+            chain.confirmation_blocks,
+            chain.contracts.clone(),
+            decoders,
+            config.rpc.clone(),
+            indexer_metrics.clone(),
+        )
+        .map_err(|e| anyhow::anyhow!("Could not create RPC provider for {}: {e}", chain.name))?;
+        ethereum_event_polling_services.push(match &chain.ws_url {
+            Some(ws_url) => Arc::new(AlloyPubsubEthereumEventPollingService::new(
+                ws_url,
+                alloy_ethereum_event_polling_service,
+            )),
+            None => Arc::new(alloy_ethereum_event_polling_service),
+        });
+    }
+
+    Ok(AppState {
         ethereum_event_repository: Arc::from(postgres_ethereum_event_repository),
-        ethereum_event_polling_service: Arc::from(alloy_ethereum_event_polling_service),
-    };
+        block_cursor_repository: Arc::from(postgres_block_cursor_repository),
+        block_header_repository: Arc::from(postgres_block_header_repository),
+        badge_repository: Arc::from(postgres_badge_repository),
+        badge_upvote_repository: Arc::from(postgres_badge_upvote_repository),
+        attestation_repository: Arc::from(postgres_attestation_repository),
+        token_balance_repository: Arc::from(postgres_token_balance_repository),
+        ethereum_event_polling_services,
+        ethereum_event_broadcaster: Arc::new(TokioEthereumEventBroadcaster::new(
+            EVENT_BROADCAST_CAPACITY,
+        )),
+        indexer_metrics,
+        ingestion_control: Arc::new(InMemoryIngestionControl::new()),
+        batch_size: config.batch_size,
+        admin_token: config.admin_token.clone(),
+    })
+}
 
-    let router = Router::new()
+pub async fn create_app(state: AppState) -> Router {
+    let mut router = Router::new()
+        .route("/health", get(health_handler))
+        .route("/ready", get(ready_handler))
+        .route("/metrics", get(metrics_handler))
         .route("/poll/", post(poll_handler))
         .route("/events/", get(list_events_handler))
+        .route("/events/stream", get(stream_events_handler))
+        .route("/events/ws", get(events_websocket_handler))
+        .route("/badges/", get(list_badges_handler))
+        .route("/badges/ranking", get(get_badge_ranking_handler))
+        .route(
+            "/badges/upvotes/{voter}",
+            get(list_badge_upvotes_by_voter_handler),
+        )
+        .route("/badges/{name}", get(get_badge_handler))
+        .route(
+            "/badges/creator/{creator}",
+            get(list_badges_by_creator_handler),
+        )
+        .route("/attestations/", get(list_attestations_handler))
+        .route("/attestations/{uid}", get(get_attestation_handler))
+        .route("/balances/top-holders", get(get_top_token_holders_handler))
+        .route(
+            "/balances/{address}",
+            get(list_token_balances_by_address_handler),
+        )
         .with_state(state.clone());
 
+    // Admin endpoints are only served once a token is configured to guard them.
+    if state.admin_token.is_some() {
+        router = router.merge(
+            Router::new()
+                .route("/admin/status", get(get_ingestion_status_handler))
+                .route(
+                    "/admin/chains/{chain_id}/contracts/{contract}/pause",
+                    post(pause_contract_handler),
+                )
+                .route(
+                    "/admin/chains/{chain_id}/contracts/{contract}/resume",
+                    post(resume_contract_handler),
+                )
+                .route(
+                    "/admin/chains/{chain_id}/contracts/{contract}/rewind",
+                    post(rewind_contract_handler),
+                )
+                .route_layer(from_fn_with_state(state.clone(), admin_auth_layer))
+                .with_state(state.clone()),
+        );
+    }
+
     router.with_state(state.clone()).layer(
         ServiceBuilder::new()
             .layer(TraceLayer::new_for_http())
@@ -44,5 +179,18 @@ pub async fn create_app(pool: sqlx::PgPool) -> Router {
 #[derive(Clone)]
 pub struct AppState {
     pub ethereum_event_repository: Arc<dyn EthereumEventRepository>,
-    pub ethereum_event_polling_service: Arc<dyn EthereumEventPollingService>,
+    pub block_cursor_repository: Arc<dyn BlockCursorRepository>,
+    pub block_header_repository: Arc<dyn BlockHeaderRepository>,
+    pub badge_repository: Arc<dyn BadgeRepository>,
+    pub badge_upvote_repository: Arc<dyn BadgeUpvoteRepository>,
+    pub attestation_repository: Arc<dyn AttestationRepository>,
+    pub token_balance_repository: Arc<dyn TokenBalanceRepository>,
+    /// One polling service per indexed chain.
+    pub ethereum_event_polling_services: Vec<Arc<dyn EthereumEventPollingService>>,
+    pub ethereum_event_broadcaster: Arc<dyn EthereumEventBroadcaster>,
+    pub indexer_metrics: Arc<dyn IndexerMetrics>,
+    pub ingestion_control: Arc<dyn IngestionControl>,
+    pub batch_size: u64,
+    /// Bearer token of the admin endpoints, which are not served when unset.
+    pub admin_token: Option<String>,
 }
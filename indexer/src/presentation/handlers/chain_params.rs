@@ -0,0 +1,7 @@
+use serde::Deserialize;
+
+/// Query string of the lookups that cover every indexed chain unless one is picked.
+#[derive(Debug, Deserialize)]
+pub struct ChainParams {
+    pub chain_id: Option<u64>,
+}
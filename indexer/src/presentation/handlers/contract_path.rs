@@ -0,0 +1,23 @@
+use serde::Deserialize;
+
+use crate::presentation::api::AppState;
+
+/// Path of the admin endpoints acting on a single contract of a chain.
+#[derive(Debug, Deserialize)]
+pub struct ContractPath {
+    pub chain_id: u64,
+    pub contract: String,
+}
+
+impl ContractPath {
+    /// Whether the contract is configured on the chain, so that operators cannot pause or
+    /// rewind a misspelled name.
+    pub fn is_indexed(&self, state: &AppState) -> bool {
+        state
+            .ethereum_event_polling_services
+            .iter()
+            .filter(|service| service.chain_id() == self.chain_id)
+            .flat_map(|service| service.contracts())
+            .any(|contract| contract.name == self.contract)
+    }
+}
@@ -0,0 +1,72 @@
+use axum::{
+    Json,
+    extract::{
+        Query, State,
+        ws::{Message, WebSocket, WebSocketUpgrade},
+    },
+    http::StatusCode,
+    response::{IntoResponse, Response},
+};
+use futures::{Stream, StreamExt};
+
+use crate::{
+    application::queries::stream_events::stream_events,
+    domain::entities::ethereum_event::EthereumEvent,
+    presentation::{api::AppState, handlers::stream_events_handler::StreamEventsParams},
+};
+
+/// WebSocket variant of the event stream, sending each event as a JSON text message.
+pub async fn events_websocket_handler(
+    State(state): State<AppState>,
+    Query(params): Query<StreamEventsParams>,
+    ws: WebSocketUpgrade,
+) -> Response {
+    let Some(filter) = params.filter() else {
+        return (
+            StatusCode::BAD_REQUEST,
+            Json(serde_json::json!({"error": "Invalid address"})),
+        )
+            .into_response();
+    };
+
+    match stream_events(
+        state.ethereum_event_repository,
+        state.ethereum_event_broadcaster,
+        filter,
+        params.last_sequence,
+    )
+    .await
+    {
+        Ok(events) => ws.on_upgrade(move |socket| forward_events(socket, events)),
+        Err(e) => (
+            StatusCode::INTERNAL_SERVER_ERROR,
+            Json(serde_json::json!({"error": e.to_string()})),
+        )
+            .into_response(),
+    }
+}
+
+async fn forward_events(
+    mut socket: WebSocket,
+    events: impl Stream<Item = EthereumEvent> + Send + 'static,
+) {
+    let mut events = Box::pin(events);
+    loop {
+        tokio::select! {
+            event = events.next() => {
+                let Some(event) = event else { break };
+                let Ok(text) = serde_json::to_string(&event) else { continue };
+                if socket.send(Message::Text(text.into())).await.is_err() {
+                    return;
+                }
+            }
+            // Incoming messages are ignored, the loop only watches for the client leaving.
+            message = socket.recv() => {
+                if !matches!(message, Some(Ok(_))) {
+                    return;
+                }
+            }
+        }
+    }
+    let _ = socket.send(Message::Close(None)).await;
+}
@@ -0,0 +1,40 @@
+use alloy::primitives::B256;
+use axum::{
+    Json,
+    extract::{Path, Query, State},
+    http::StatusCode,
+    response::{IntoResponse, Response},
+};
+
+use crate::{
+    application::queries::get_attestation::get_attestation,
+    presentation::{api::AppState, handlers::chain_params::ChainParams},
+};
+
+pub async fn get_attestation_handler(
+    State(state): State<AppState>,
+    Path(uid): Path<String>,
+    Query(params): Query<ChainParams>,
+) -> Response {
+    let Ok(uid) = uid.parse::<B256>() else {
+        return (
+            StatusCode::BAD_REQUEST,
+            Json(serde_json::json!({"error": "Invalid attestation uid"})),
+        )
+            .into_response();
+    };
+
+    match get_attestation(state.attestation_repository, params.chain_id, uid).await {
+        Ok(Some(attestation)) => Json(attestation).into_response(),
+        Ok(None) => (
+            StatusCode::NOT_FOUND,
+            Json(serde_json::json!({"error": "Attestation not found"})),
+        )
+            .into_response(),
+        Err(e) => (
+            StatusCode::INTERNAL_SERVER_ERROR,
+            Json(serde_json::json!({"error": e.to_string()})),
+        )
+            .into_response(),
+    }
+}
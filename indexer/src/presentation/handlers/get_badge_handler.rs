@@ -0,0 +1,31 @@
+use axum::{
+    Json,
+    extract::{Path, Query, State},
+    http::StatusCode,
+    response::{IntoResponse, Response},
+};
+
+use crate::{
+    application::queries::get_badge::get_badge,
+    presentation::{api::AppState, handlers::chain_params::ChainParams},
+};
+
+pub async fn get_badge_handler(
+    State(state): State<AppState>,
+    Path(name): Path<String>,
+    Query(params): Query<ChainParams>,
+) -> Response {
+    match get_badge(state.badge_repository, params.chain_id, name).await {
+        Ok(Some(badge)) => Json(badge).into_response(),
+        Ok(None) => (
+            StatusCode::NOT_FOUND,
+            Json(serde_json::json!({"error": "Badge not found"})),
+        )
+            .into_response(),
+        Err(e) => (
+            StatusCode::INTERNAL_SERVER_ERROR,
+            Json(serde_json::json!({"error": e.to_string()})),
+        )
+            .into_response(),
+    }
+}
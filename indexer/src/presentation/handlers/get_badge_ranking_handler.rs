@@ -0,0 +1,47 @@
+use axum::{
+    Json,
+    extract::{Query, State},
+    http::StatusCode,
+    response::{IntoResponse, Response},
+};
+use serde::Deserialize;
+
+use crate::{
+    application::queries::get_badge_ranking::get_badge_ranking,
+    domain::entities::{badge_upvote::BadgeRankingQuery, sort_order::SortOrder},
+    presentation::api::AppState,
+};
+
+const DEFAULT_LIMIT: u64 = 50;
+const MAX_LIMIT: u64 = 500;
+
+#[derive(Debug, Deserialize)]
+pub struct BadgeRankingParams {
+    pub chain_id: Option<u64>,
+    #[serde(default)]
+    pub order: SortOrder,
+    pub limit: Option<u64>,
+    #[serde(default)]
+    pub offset: u64,
+}
+
+pub async fn get_badge_ranking_handler(
+    State(state): State<AppState>,
+    Query(params): Query<BadgeRankingParams>,
+) -> Response {
+    let query = BadgeRankingQuery {
+        chain_id: params.chain_id,
+        order: params.order,
+        limit: params.limit.unwrap_or(DEFAULT_LIMIT).min(MAX_LIMIT),
+        offset: params.offset,
+    };
+
+    match get_badge_ranking(state.badge_upvote_repository, query).await {
+        Ok(ranking) => Json(ranking).into_response(),
+        Err(e) => (
+            StatusCode::INTERNAL_SERVER_ERROR,
+            Json(serde_json::json!({"error": e.to_string()})),
+        )
+            .into_response(),
+    }
+}
@@ -0,0 +1,27 @@
+use axum::{
+    Json,
+    extract::State,
+    http::StatusCode,
+    response::{IntoResponse, Response},
+};
+
+use crate::{
+    application::queries::get_ingestion_status::get_ingestion_status, presentation::api::AppState,
+};
+
+pub async fn get_ingestion_status_handler(State(state): State<AppState>) -> Response {
+    match get_ingestion_status(
+        state.ethereum_event_polling_services,
+        state.block_cursor_repository,
+        state.ingestion_control,
+    )
+    .await
+    {
+        Ok(chains) => Json(serde_json::json!({"chains": chains})).into_response(),
+        Err(e) => (
+            StatusCode::INTERNAL_SERVER_ERROR,
+            Json(serde_json::json!({"error": e.to_string()})),
+        )
+            .into_response(),
+    }
+}
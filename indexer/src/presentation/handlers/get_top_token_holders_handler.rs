@@ -0,0 +1,43 @@
+use axum::{
+    Json,
+    extract::{Query, State},
+    http::StatusCode,
+    response::{IntoResponse, Response},
+};
+use serde::Deserialize;
+
+use crate::{
+    application::queries::get_top_token_holders::get_top_token_holders,
+    domain::entities::token_balance::TopHoldersQuery, presentation::api::AppState,
+};
+
+const DEFAULT_LIMIT: u64 = 50;
+const MAX_LIMIT: u64 = 500;
+
+#[derive(Debug, Deserialize)]
+pub struct TopHoldersParams {
+    pub chain_id: Option<u64>,
+    pub limit: Option<u64>,
+    #[serde(default)]
+    pub offset: u64,
+}
+
+pub async fn get_top_token_holders_handler(
+    State(state): State<AppState>,
+    Query(params): Query<TopHoldersParams>,
+) -> Response {
+    let query = TopHoldersQuery {
+        chain_id: params.chain_id,
+        limit: params.limit.unwrap_or(DEFAULT_LIMIT).min(MAX_LIMIT),
+        offset: params.offset,
+    };
+
+    match get_top_token_holders(state.token_balance_repository, query).await {
+        Ok(holders) => Json(holders).into_response(),
+        Err(e) => (
+            StatusCode::INTERNAL_SERVER_ERROR,
+            Json(serde_json::json!({"error": e.to_string()})),
+        )
+            .into_response(),
+    }
+}
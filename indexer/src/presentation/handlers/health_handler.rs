@@ -0,0 +1,6 @@
+use axum::{Json, response::IntoResponse};
+
+/// Answers as long as the process serves requests, whatever the state of its dependencies.
+pub async fn health_handler() -> impl IntoResponse {
+    Json(serde_json::json!({"status": "ok"}))
+}
@@ -0,0 +1,55 @@
+use alloy::primitives::Address;
+use axum::{
+    Json,
+    extract::{Query, State},
+    http::StatusCode,
+    response::{IntoResponse, Response},
+};
+use serde::Deserialize;
+
+use crate::{
+    application::queries::list_attestations::list_attestations,
+    domain::entities::attestation::AttestationFilter, presentation::api::AppState,
+};
+
+#[derive(Debug, Deserialize)]
+pub struct ListAttestationsParams {
+    pub chain_id: Option<u64>,
+    pub attester: Option<String>,
+    pub recipient: Option<String>,
+    pub badge_name: Option<String>,
+    #[serde(default)]
+    pub include_revoked: bool,
+}
+
+pub async fn list_attestations_handler(
+    State(state): State<AppState>,
+    Query(params): Query<ListAttestationsParams>,
+) -> Response {
+    let (Ok(attester), Ok(recipient)) = (
+        params.attester.map(|a| a.parse::<Address>()).transpose(),
+        params.recipient.map(|a| a.parse::<Address>()).transpose(),
+    ) else {
+        return (
+            StatusCode::BAD_REQUEST,
+            Json(serde_json::json!({"error": "Invalid address"})),
+        )
+            .into_response();
+    };
+    let filter = AttestationFilter {
+        chain_id: params.chain_id,
+        attester,
+        recipient,
+        badge_name: params.badge_name,
+        include_revoked: params.include_revoked,
+    };
+
+    match list_attestations(state.attestation_repository, filter).await {
+        Ok(attestations) => Json(attestations).into_response(),
+        Err(e) => (
+            StatusCode::INTERNAL_SERVER_ERROR,
+            Json(serde_json::json!({"error": e.to_string()})),
+        )
+            .into_response(),
+    }
+}
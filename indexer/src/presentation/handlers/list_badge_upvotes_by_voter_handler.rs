@@ -0,0 +1,35 @@
+use alloy::primitives::Address;
+use axum::{
+    Json,
+    extract::{Path, Query, State},
+    http::StatusCode,
+    response::{IntoResponse, Response},
+};
+
+use crate::{
+    application::queries::list_badge_upvotes_by_voter::list_badge_upvotes_by_voter,
+    presentation::{api::AppState, handlers::chain_params::ChainParams},
+};
+
+pub async fn list_badge_upvotes_by_voter_handler(
+    State(state): State<AppState>,
+    Path(voter): Path<String>,
+    Query(params): Query<ChainParams>,
+) -> Response {
+    let Ok(voter) = voter.parse::<Address>() else {
+        return (
+            StatusCode::BAD_REQUEST,
+            Json(serde_json::json!({"error": "Invalid voter address"})),
+        )
+            .into_response();
+    };
+
+    match list_badge_upvotes_by_voter(state.badge_upvote_repository, params.chain_id, voter).await {
+        Ok(upvotes) => Json(upvotes).into_response(),
+        Err(e) => (
+            StatusCode::INTERNAL_SERVER_ERROR,
+            Json(serde_json::json!({"error": e.to_string()})),
+        )
+            .into_response(),
+    }
+}
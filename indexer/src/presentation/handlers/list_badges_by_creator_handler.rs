@@ -0,0 +1,35 @@
+use alloy::primitives::Address;
+use axum::{
+    Json,
+    extract::{Path, Query, State},
+    http::StatusCode,
+    response::{IntoResponse, Response},
+};
+
+use crate::{
+    application::queries::list_badges_by_creator::list_badges_by_creator,
+    presentation::{api::AppState, handlers::chain_params::ChainParams},
+};
+
+pub async fn list_badges_by_creator_handler(
+    State(state): State<AppState>,
+    Path(creator): Path<String>,
+    Query(params): Query<ChainParams>,
+) -> Response {
+    let Ok(creator) = creator.parse::<Address>() else {
+        return (
+            StatusCode::BAD_REQUEST,
+            Json(serde_json::json!({"error": "Invalid creator address"})),
+        )
+            .into_response();
+    };
+
+    match list_badges_by_creator(state.badge_repository, params.chain_id, creator).await {
+        Ok(badges) => Json(badges).into_response(),
+        Err(e) => (
+            StatusCode::INTERNAL_SERVER_ERROR,
+            Json(serde_json::json!({"error": e.to_string()})),
+        )
+            .into_response(),
+    }
+}
@@ -0,0 +1,25 @@
+use axum::{
+    Json,
+    extract::{Query, State},
+    http::StatusCode,
+    response::{IntoResponse, Response},
+};
+
+use crate::{
+    application::queries::list_badges::list_badges,
+    presentation::{api::AppState, handlers::chain_params::ChainParams},
+};
+
+pub async fn list_badges_handler(
+    State(state): State<AppState>,
+    Query(params): Query<ChainParams>,
+) -> Response {
+    match list_badges(state.badge_repository, params.chain_id).await {
+        Ok(badges) => Json(badges).into_response(),
+        Err(e) => (
+            StatusCode::INTERNAL_SERVER_ERROR,
+            Json(serde_json::json!({"error": e.to_string()})),
+        )
+            .into_response(),
+    }
+}
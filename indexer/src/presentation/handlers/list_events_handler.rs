@@ -0,0 +1,111 @@
+use alloy::primitives::Address;
+use axum::{
+    Json,
+    extract::{Query, State},
+    http::StatusCode,
+    response::{IntoResponse, Response},
+};
+use chrono::{DateTime, Utc};
+use serde::Deserialize;
+
+use crate::{
+    application::queries::list_events::list_events,
+    domain::entities::{
+        ethereum_event::EventStatus,
+        ethereum_event_query::{EthereumEventQuery, EventCursor},
+        sort_order::SortOrder,
+    },
+    presentation::api::AppState,
+};
+
+const DEFAULT_LIMIT: u64 = 100;
+const MAX_LIMIT: u64 = 1000;
+
+/// Finality of the events to list. Events of any status are listed unless asked otherwise;
+/// consumers acting on them ask for finalized ones, which a shallow reorg can no longer remove.
+#[derive(Debug, Clone, Copy, Default, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum EventStatusFilter {
+    Pending,
+    Finalized,
+    #[default]
+    #[serde(alias = "all")]
+    Any,
+}
+
+impl EventStatusFilter {
+    fn status(self) -> Option<EventStatus> {
+        match self {
+            Self::Pending => Some(EventStatus::Pending),
+            Self::Finalized => Some(EventStatus::Finalized),
+            Self::Any => None,
+        }
+    }
+}
+
+#[derive(Debug, Deserialize)]
+pub struct ListEventsParams {
+    pub chain_id: Option<u64>,
+    pub event_type: Option<String>,
+    pub recipient: Option<String>,
+    pub creator: Option<String>,
+    pub from_block: Option<u64>,
+    pub to_block: Option<u64>,
+    pub from_time: Option<DateTime<Utc>>,
+    pub to_time: Option<DateTime<Utc>>,
+    #[serde(default)]
+    pub status: EventStatusFilter,
+    pub cursor: Option<String>,
+    #[serde(default)]
+    pub order: SortOrder,
+    pub limit: Option<u64>,
+}
+
+pub async fn list_events_handler(
+    State(state): State<AppState>,
+    Query(params): Query<ListEventsParams>,
+) -> Response {
+    let (Ok(recipient), Ok(creator)) = (
+        params.recipient.map(|a| a.parse::<Address>()).transpose(),
+        params.creator.map(|a| a.parse::<Address>()).transpose(),
+    ) else {
+        return (
+            StatusCode::BAD_REQUEST,
+            Json(serde_json::json!({"error": "Invalid address"})),
+        )
+            .into_response();
+    };
+    let cursor = match params.cursor.map(|c| c.parse::<EventCursor>()).transpose() {
+        Ok(cursor) => cursor,
+        Err(e) => {
+            return (
+                StatusCode::BAD_REQUEST,
+                Json(serde_json::json!({"error": e})),
+            )
+                .into_response();
+        }
+    };
+    let query = EthereumEventQuery {
+        chain_id: params.chain_id,
+        event_type: params.event_type,
+        recipient,
+        creator,
+        from_block: params.from_block,
+        to_block: params.to_block,
+        from_time: params.from_time,
+        to_time: params.to_time,
+        status: params.status.status(),
+        cursor,
+        order: params.order,
+        limit: params.limit.unwrap_or(DEFAULT_LIMIT).clamp(1, MAX_LIMIT),
+    };
+
+    match list_events(state.ethereum_event_repository, query).await {
+        Ok(page) => Json(page).into_response(),
+        Err(e) => (
+            StatusCode::INTERNAL_SERVER_ERROR,
+            Json(serde_json::json!({"error": e.to_string()})),
+        )
+            .into_response(),
+    }
+}
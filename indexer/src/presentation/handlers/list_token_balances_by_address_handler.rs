@@ -0,0 +1,37 @@
+use alloy::primitives::Address;
+use axum::{
+    Json,
+    extract::{Path, Query, State},
+    http::StatusCode,
+    response::{IntoResponse, Response},
+};
+
+use crate::{
+    application::queries::list_token_balances_by_address::list_token_balances_by_address,
+    presentation::{api::AppState, handlers::chain_params::ChainParams},
+};
+
+pub async fn list_token_balances_by_address_handler(
+    State(state): State<AppState>,
+    Path(address): Path<String>,
+    Query(params): Query<ChainParams>,
+) -> Response {
+    let Ok(address) = address.parse::<Address>() else {
+        return (
+            StatusCode::BAD_REQUEST,
+            Json(serde_json::json!({"error": "Invalid address"})),
+        )
+            .into_response();
+    };
+
+    match list_token_balances_by_address(state.token_balance_repository, params.chain_id, address)
+        .await
+    {
+        Ok(balances) => Json(balances).into_response(),
+        Err(e) => (
+            StatusCode::INTERNAL_SERVER_ERROR,
+            Json(serde_json::json!({"error": e.to_string()})),
+        )
+            .into_response(),
+    }
+}
@@ -1,2 +1,22 @@
+pub mod chain_params;
+pub mod contract_path;
+pub mod events_websocket_handler;
+pub mod get_attestation_handler;
+pub mod get_badge_handler;
+pub mod get_badge_ranking_handler;
+pub mod get_ingestion_status_handler;
+pub mod get_top_token_holders_handler;
+pub mod health_handler;
+pub mod list_attestations_handler;
+pub mod list_badge_upvotes_by_voter_handler;
+pub mod list_badges_by_creator_handler;
+pub mod list_badges_handler;
 pub mod list_events_handler;
+pub mod list_token_balances_by_address_handler;
+pub mod metrics_handler;
+pub mod pause_contract_handler;
 pub mod poll_handler;
+pub mod ready_handler;
+pub mod resume_contract_handler;
+pub mod rewind_contract_handler;
+pub mod stream_events_handler;
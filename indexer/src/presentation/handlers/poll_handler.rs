@@ -1,14 +1,53 @@
-use axum::{extract::State, http::StatusCode};
+use axum::{
+    Json,
+    extract::State,
+    http::StatusCode,
+    response::{IntoResponse, Response},
+};
+use serde_json::json;
 
 use crate::{application::commands::poll::poll, presentation::api::AppState};
 
+/// Polls every chain once. A chain failing does not keep the others from being polled; the
+/// failures are reported together, as a bad gateway when they all come from the RPC endpoints.
 #[axum::debug_handler]
-pub async fn poll_handler(State(state): State<AppState>) -> StatusCode {
-    poll(
-        state.ethereum_event_polling_service,
-        state.ethereum_event_repository,
+pub async fn poll_handler(State(state): State<AppState>) -> Response {
+    let chains = state.ethereum_event_polling_services.len();
+    let mut failures = Vec::new();
+    let mut rpc_failures_only = true;
+    for ethereum_event_polling_service in state.ethereum_event_polling_services {
+        let chain_id = ethereum_event_polling_service.chain_id();
+        if let Err(e) = poll(
+            ethereum_event_polling_service,
+            state.ethereum_event_repository.clone(),
+            state.block_cursor_repository.clone(),
+            state.block_header_repository.clone(),
+            state.ethereum_event_broadcaster.clone(),
+            state.indexer_metrics.clone(),
+            state.ingestion_control.clone(),
+            state.batch_size,
+        )
+        .await
+        {
+            rpc_failures_only &= e.is::<alloy::transports::TransportError>();
+            failures.push(json!({"chain_id": chain_id, "error": e.to_string()}));
+        }
+    }
+
+    if failures.is_empty() {
+        return StatusCode::CREATED.into_response();
+    }
+    let status = if rpc_failures_only {
+        StatusCode::BAD_GATEWAY
+    } else {
+        StatusCode::INTERNAL_SERVER_ERROR
+    };
+    (
+        status,
+        Json(json!({
+            "error": format!("Polling failed for {} of {chains} chains", failures.len()),
+            "chains": failures,
+        })),
     )
-    .await
-    .unwrap();
-    StatusCode::CREATED
+        .into_response()
 }
@@ -0,0 +1,34 @@
+use std::time::Duration;
+
+use axum::{
+    Json,
+    extract::State,
+    http::StatusCode,
+    response::{IntoResponse, Response},
+};
+
+use crate::{application::queries::get_readiness::get_readiness, presentation::api::AppState};
+
+/// Time given to the RPC endpoints and the database to answer, so that probes fail fast rather
+/// than wait for every RPC retry.
+const CHECK_TIMEOUT: Duration = Duration::from_secs(5);
+
+pub async fn ready_handler(State(state): State<AppState>) -> Response {
+    let chains = get_readiness(
+        state.ethereum_event_polling_services,
+        state.block_cursor_repository,
+        CHECK_TIMEOUT,
+    )
+    .await;
+    let ready = chains.iter().all(|chain| chain.is_ready());
+    let status = if ready {
+        StatusCode::OK
+    } else {
+        StatusCode::SERVICE_UNAVAILABLE
+    };
+    (
+        status,
+        Json(serde_json::json!({"ready": ready, "chains": chains})),
+    )
+        .into_response()
+}
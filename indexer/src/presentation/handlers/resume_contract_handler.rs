@@ -0,0 +1,40 @@
+use axum::{
+    Json,
+    extract::{Path, State},
+    http::StatusCode,
+    response::{IntoResponse, Response},
+};
+
+use crate::{
+    application::commands::set_contract_paused::set_contract_paused,
+    presentation::{api::AppState, handlers::contract_path::ContractPath},
+};
+
+pub async fn resume_contract_handler(
+    State(state): State<AppState>,
+    Path(path): Path<ContractPath>,
+) -> Response {
+    if !path.is_indexed(&state) {
+        return (
+            StatusCode::NOT_FOUND,
+            Json(serde_json::json!({"error": "Contract not found"})),
+        )
+            .into_response();
+    }
+    match set_contract_paused(
+        state.block_cursor_repository,
+        state.ingestion_control,
+        path.chain_id,
+        &path.contract,
+        false,
+    )
+    .await
+    {
+        Ok(()) => StatusCode::NO_CONTENT.into_response(),
+        Err(e) => (
+            StatusCode::INTERNAL_SERVER_ERROR,
+            Json(serde_json::json!({"error": e.to_string()})),
+        )
+            .into_response(),
+    }
+}
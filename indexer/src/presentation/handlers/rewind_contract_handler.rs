@@ -0,0 +1,52 @@
+use axum::{
+    Json,
+    extract::{Path, State},
+    http::StatusCode,
+    response::{IntoResponse, Response},
+};
+use serde::Deserialize;
+
+use crate::{
+    application::commands::rewind_contract::{InvalidRewind, rewind_contract},
+    presentation::{api::AppState, handlers::contract_path::ContractPath},
+};
+
+#[derive(Debug, Deserialize)]
+pub struct RewindContractRequest {
+    /// Last block to keep, ingestion resumes right after it.
+    pub block_number: u64,
+}
+
+pub async fn rewind_contract_handler(
+    State(state): State<AppState>,
+    Path(path): Path<ContractPath>,
+    Json(request): Json<RewindContractRequest>,
+) -> Response {
+    if !path.is_indexed(&state) {
+        return (
+            StatusCode::NOT_FOUND,
+            Json(serde_json::json!({"error": "Contract not found"})),
+        )
+            .into_response();
+    }
+    match rewind_contract(
+        state.ethereum_event_repository,
+        state.block_cursor_repository,
+        state.ingestion_control,
+        path.chain_id,
+        &path.contract,
+        request.block_number,
+    )
+    .await
+    {
+        Ok(()) => StatusCode::NO_CONTENT.into_response(),
+        Err(e) => {
+            let status = if e.is::<InvalidRewind>() {
+                StatusCode::CONFLICT
+            } else {
+                StatusCode::INTERNAL_SERVER_ERROR
+            };
+            (status, Json(serde_json::json!({"error": e.to_string()}))).into_response()
+        }
+    }
+}
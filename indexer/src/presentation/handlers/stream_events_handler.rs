@@ -0,0 +1,99 @@
+use std::convert::Infallible;
+
+use alloy::primitives::Address;
+use axum::{
+    Json,
+    extract::{Query, State},
+    http::{HeaderMap, StatusCode},
+    response::{
+        IntoResponse, Response,
+        sse::{Event, KeepAlive, Sse},
+    },
+};
+use futures::StreamExt;
+use serde::Deserialize;
+
+use crate::{
+    application::queries::stream_events::stream_events,
+    domain::entities::ethereum_event_query::EventStreamFilter, presentation::api::AppState,
+};
+
+#[derive(Debug, Deserialize)]
+pub struct StreamEventsParams {
+    pub chain_id: Option<u64>,
+    pub event_type: Option<String>,
+    pub address: Option<String>,
+    /// Sequence number of the last event received, a cursor in commit order rather than an
+    /// event id.
+    pub last_sequence: Option<u64>,
+}
+
+impl StreamEventsParams {
+    /// Returns `None` when the address is invalid.
+    pub fn filter(&self) -> Option<EventStreamFilter> {
+        let address = match &self.address {
+            Some(address) => Some(address.parse::<Address>().ok()?),
+            None => None,
+        };
+        Some(EventStreamFilter {
+            chain_id: self.chain_id,
+            event_type: self.event_type.clone(),
+            address,
+        })
+    }
+}
+
+/// Server-sent events of newly committed events, identified by their sequence number. Browsers
+/// reconnecting after a drop resume from the `Last-Event-ID` header, which carries the same sequence
+/// number and takes precedence over `last_sequence`.
+pub async fn stream_events_handler(
+    State(state): State<AppState>,
+    headers: HeaderMap,
+    Query(params): Query<StreamEventsParams>,
+) -> Response {
+    let Some(filter) = params.filter() else {
+        return (
+            StatusCode::BAD_REQUEST,
+            Json(serde_json::json!({"error": "Invalid address"})),
+        )
+            .into_response();
+    };
+    let last_sequence = match headers.get("last-event-id") {
+        Some(value) => match value.to_str().ok().and_then(|value| value.parse().ok()) {
+            Some(sequence) => Some(sequence),
+            None => {
+                return (
+                    StatusCode::BAD_REQUEST,
+                    Json(serde_json::json!({"error": "Invalid last event id"})),
+                )
+                    .into_response();
+            }
+        },
+        None => params.last_sequence,
+    };
+
+    match stream_events(
+        state.ethereum_event_repository,
+        state.ethereum_event_broadcaster,
+        filter,
+        last_sequence,
+    )
+    .await
+    {
+        Ok(events) => Sse::new(events.map(|event| {
+            Ok::<_, Infallible>(
+                Event::default()
+                    .id(event.sequence.to_string())
+                    .event(event.event_type.name())
+                    .data(serde_json::to_string(&event).unwrap_or_default()),
+            )
+        }))
+        .keep_alive(KeepAlive::default())
+        .into_response(),
+        Err(e) => (
+            StatusCode::INTERNAL_SERVER_ERROR,
+            Json(serde_json::json!({"error": e.to_string()})),
+        )
+            .into_response(),
+    }
+}
@@ -0,0 +1,38 @@
+use alloy::primitives::keccak256;
+use axum::{
+    body::Body,
+    extract::{Request, State},
+    http::StatusCode,
+    middleware::Next,
+    response::Response,
+};
+use subtle::ConstantTimeEq;
+
+use super::api::AppState;
+
+/// Lets through the requests bearing the configured admin token.
+pub async fn admin_auth_layer(
+    State(state): State<AppState>,
+    req: Request<Body>,
+    next: Next,
+) -> Result<Response, StatusCode> {
+    let admin_token = state
+        .admin_token
+        .as_deref()
+        .ok_or(StatusCode::UNAUTHORIZED)?;
+    let token = req
+        .headers()
+        .get("authorization")
+        .and_then(|v| v.to_str().ok())
+        .and_then(|v| v.strip_prefix("Bearer "))
+        .ok_or(StatusCode::UNAUTHORIZED)?;
+    // Hashes have the same length whatever the token's, and are compared in constant time
+    if !bool::from(
+        keccak256(token)
+            .as_slice()
+            .ct_eq(keccak256(admin_token).as_slice()),
+    ) {
+        return Err(StatusCode::UNAUTHORIZED);
+    }
+    Ok(next.run(req).await)
+}